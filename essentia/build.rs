@@ -1,16 +1,45 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use essentia_codegen::IntrospectionSnapshot;
 
 fn main() -> std::io::Result<()> {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=ESSENTIA_INTROSPECTION_SNAPSHOT");
+
+    let directory = Path::new(&std::env::var("OUT_DIR").unwrap()).to_path_buf();
+
+    let bundled_snapshot =
+        Path::new(&std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("introspection.json");
+
+    let snapshot_path = match std::env::var_os("ESSENTIA_INTROSPECTION_SNAPSHOT") {
+        Some(path) => Some(PathBuf::from(path)),
+        None => bundled_snapshot.exists().then(|| bundled_snapshot.clone()),
+    };
+
+    if let Some(snapshot_path) = snapshot_path {
+        println!("cargo:rerun-if-changed={}", snapshot_path.display());
+
+        let snapshot = IntrospectionSnapshot::load(&snapshot_path)?;
+        return essentia_codegen::generate_code_from_snapshot(&directory, &snapshot);
+    }
+
     if std::env::var("DOCS_RS").is_ok() {
         println!("cargo:warning=Skipping build.rs on docs.rs");
         return Ok(());
     }
 
-    println!("cargo:rerun-if-changed=build.rs");
-
-    let directory = Path::new(&std::env::var("OUT_DIR").unwrap()).join("algorithms");
-
-    essentia_codegen::generate_code(&directory)?;
+    #[cfg(feature = "introspect")]
+    return essentia_codegen::generate_code(&directory);
 
-    Ok(())
+    #[cfg(not(feature = "introspect"))]
+    {
+        println!(
+            "cargo:error=No introspection snapshot found and the `introspect` feature is disabled"
+        );
+        println!(
+            "cargo:error=Set ESSENTIA_INTROSPECTION_SNAPSHOT or generate {} with the essentia-snapshot binary",
+            bundled_snapshot.display()
+        );
+        std::process::exit(1);
+    }
 }
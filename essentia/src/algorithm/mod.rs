@@ -1,12 +1,21 @@
-pub use essentia_core::algorithm::{Configured, Initialized};
+pub use essentia_core::algorithm::{
+    Configured, DynamicAlgorithm, DynamicComputeError, Initialized, ParameterError,
+};
 
 mod error;
 pub use error::*;
 
-use crate::Essentia;
+use crate::{AlgorithmError, Essentia};
 
-pub trait CreateAlgorithm<'a> {
-    fn create(essentia: &'a Essentia) -> Self;
+pub trait CreateAlgorithm<'a>: Sized {
+    fn try_create(essentia: &'a Essentia) -> Result<Self, AlgorithmError>;
+
+    fn create(essentia: &'a Essentia) -> Self {
+        match Self::try_create(essentia) {
+            Ok(algorithm) => algorithm,
+            Err(error) => panic!("Failed to create algorithm: {}", error),
+        }
+    }
 }
 
 include!(concat!(env!("OUT_DIR"), "/algorithms/mod.rs"));
@@ -0,0 +1,114 @@
+use std::{fs::File, io::Cursor, path::Path};
+
+use symphonia::core::{
+    audio::SampleBuffer,
+    codecs::{CODEC_TYPE_NULL, DecoderOptions},
+    errors::Error as SymphoniaError,
+    formats::FormatOptions,
+    io::{MediaSource, MediaSourceStream},
+    meta::MetadataOptions,
+    probe::Hint,
+};
+
+use crate::audio::{AudioBuffer, AudioError};
+
+pub fn decode(path: impl AsRef<Path>) -> Result<AudioBuffer, AudioError> {
+    let path = path.as_ref();
+    let file = File::open(path)?;
+
+    let extension = path.extension().and_then(|extension| extension.to_str());
+
+    decode_source(Box::new(file), extension)
+}
+
+/// Decodes an in-memory file. The extension, e.g. `"mp3"`, is only used as a
+/// hint to speed up format detection.
+pub fn decode_bytes(bytes: Vec<u8>, extension: Option<&str>) -> Result<AudioBuffer, AudioError> {
+    decode_source(Box::new(Cursor::new(bytes)), extension)
+}
+
+fn decode_source(
+    source: Box<dyn MediaSource>,
+    extension: Option<&str>,
+) -> Result<AudioBuffer, AudioError> {
+    let media_source_stream = MediaSourceStream::new(source, Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(extension) = extension {
+        hint.with_extension(extension);
+    }
+
+    let probed = symphonia::default::get_probe().format(
+        &hint,
+        media_source_stream,
+        &FormatOptions::default(),
+        &MetadataOptions::default(),
+    )?;
+
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|track| track.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or(AudioError::NoAudioTrack)?;
+
+    let track_id = track.id;
+    let mut sample_rate = track.codec_params.sample_rate.unwrap_or(0);
+    let mut channels = track
+        .codec_params
+        .channels
+        .map(|channels| channels.count())
+        .unwrap_or(0);
+
+    let mut decoder =
+        symphonia::default::get_codecs().make(&track.codec_params, &DecoderOptions::default())?;
+
+    let mut samples = Vec::new();
+    let mut sample_buffer: Option<SampleBuffer<f32>> = None;
+
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(error))
+                if error.kind() == std::io::ErrorKind::UnexpectedEof =>
+            {
+                break;
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            // Corrupted packets are skipped, like ffmpeg does for Essentia's loaders.
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(error) => return Err(error.into()),
+        };
+
+        let spec = *decoded.spec();
+        sample_rate = spec.rate;
+        channels = spec.channels.count();
+
+        let buffer = match &mut sample_buffer {
+            Some(buffer) if buffer.capacity() >= decoded.capacity() * channels => buffer,
+            _ => sample_buffer.insert(SampleBuffer::new(decoded.capacity() as u64, spec)),
+        };
+
+        buffer.copy_interleaved_ref(decoded);
+        samples.extend_from_slice(buffer.samples());
+    }
+
+    if channels == 0 {
+        return Err(AudioError::NoAudioTrack);
+    }
+
+    if sample_rate == 0 {
+        return Err(AudioError::UnsupportedSampleRate { sample_rate });
+    }
+
+    Ok(AudioBuffer::new(samples, channels, sample_rate))
+}
@@ -0,0 +1,22 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum AudioError {
+    #[error("Failed to read audio: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to decode audio: {0}")]
+    Decode(#[from] symphonia::core::errors::Error),
+
+    #[error("No decodable audio track found")]
+    NoAudioTrack,
+
+    #[error("Unsupported sample rate: {sample_rate}")]
+    UnsupportedSampleRate { sample_rate: u32 },
+
+    #[error("Failed to create resampler: {0}")]
+    ResamplerConstruction(#[from] rubato::ResamplerConstructionError),
+
+    #[error("Failed to resample audio: {0}")]
+    Resample(#[from] rubato::ResampleError),
+}
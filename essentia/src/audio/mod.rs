@@ -0,0 +1,186 @@
+//! Pure-Rust audio loading, so that signals can be fed to algorithms without
+//! Essentia being built against libav.
+//!
+//! WAV, FLAC, OGG/Vorbis and MP3 files are decoded with symphonia. The loaded
+//! signal can be downmixed and resampled the same way `MonoLoader` and
+//! `AudioLoader` do, producing data that can be passed directly as
+//! `VectorFloat` or `VectorStereoSample` inputs.
+
+mod decode;
+mod error;
+mod resample;
+
+use std::{path::Path, time::Duration};
+
+use crate::data::StereoSample;
+
+pub use decode::{decode, decode_bytes};
+pub use error::AudioError;
+
+/// How multi-channel audio is reduced to a single channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Downmix {
+    /// Average of all channels
+    #[default]
+    Mix,
+    /// First channel only
+    Left,
+    /// Second channel only, falling back to the first one for mono audio
+    Right,
+}
+
+/// Decoded audio with interleaved samples in the range `[-1.0, 1.0]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudioBuffer {
+    samples: Vec<f32>,
+    channels: usize,
+    sample_rate: u32,
+}
+
+impl AudioBuffer {
+    /// Creates a buffer from interleaved samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channels` is zero or the number of samples is not a multiple
+    /// of `channels`.
+    pub fn new(samples: Vec<f32>, channels: usize, sample_rate: u32) -> Self {
+        assert!(channels > 0, "Audio must have at least one channel");
+        assert!(
+            samples.len() % channels == 0,
+            "Sample count {} is not a multiple of the channel count {}",
+            samples.len(),
+            channels
+        );
+
+        Self {
+            samples,
+            channels,
+            sample_rate,
+        }
+    }
+
+    pub fn samples(&self) -> &[f32] {
+        &self.samples
+    }
+
+    pub fn into_samples(self) -> Vec<f32> {
+        self.samples
+    }
+
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// Number of samples per channel.
+    pub fn frames(&self) -> usize {
+        self.samples.len() / self.channels
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f64(self.frames() as f64 / self.sample_rate as f64)
+    }
+
+    /// Samples of a single channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channel` is out of range.
+    pub fn channel(&self, channel: usize) -> Vec<f32> {
+        assert!(
+            channel < self.channels,
+            "Channel {} out of range for audio with {} channels",
+            channel,
+            self.channels
+        );
+
+        self.samples
+            .iter()
+            .skip(channel)
+            .step_by(self.channels)
+            .copied()
+            .collect()
+    }
+
+    pub fn to_mono(&self, downmix: Downmix) -> Vec<f32> {
+        match downmix {
+            Downmix::Mix => self
+                .samples
+                .chunks_exact(self.channels)
+                .map(|frame| frame.iter().sum::<f32>() / self.channels as f32)
+                .collect(),
+            Downmix::Left => self.channel(0),
+            Downmix::Right => self.channel(1.min(self.channels - 1)),
+        }
+    }
+
+    /// Converts to stereo samples. Mono audio is duplicated on both sides and
+    /// any channel after the first two is dropped.
+    pub fn to_stereo(&self) -> Vec<StereoSample> {
+        let right = 1.min(self.channels - 1);
+
+        self.samples
+            .chunks_exact(self.channels)
+            .map(|frame| StereoSample {
+                left: frame[0],
+                right: frame[right],
+            })
+            .collect()
+    }
+
+    /// Resamples all channels to `sample_rate` with a band-limited sinc
+    /// interpolator. Returns the buffer unchanged if the rate already matches.
+    pub fn resample(self, sample_rate: u32) -> Result<Self, AudioError> {
+        if sample_rate == 0 {
+            return Err(AudioError::UnsupportedSampleRate { sample_rate });
+        }
+
+        if sample_rate == self.sample_rate || self.samples.is_empty() {
+            return Ok(Self {
+                sample_rate,
+                ..self
+            });
+        }
+
+        let planar: Vec<Vec<f32>> = (0..self.channels)
+            .map(|channel| self.channel(channel))
+            .collect();
+
+        let resampled = resample::resample_planar(&planar, self.sample_rate, sample_rate)?;
+
+        let frames = resampled[0].len();
+        let mut samples = Vec::with_capacity(frames * self.channels);
+        for frame in 0..frames {
+            samples.extend(resampled.iter().map(|channel| channel[frame]));
+        }
+
+        Ok(Self::new(samples, self.channels, sample_rate))
+    }
+}
+
+/// Loads an audio file as a mono signal at the given sample rate, like
+/// Essentia's `MonoLoader`.
+pub fn load_mono(
+    path: impl AsRef<Path>,
+    sample_rate: u32,
+    downmix: Downmix,
+) -> Result<Vec<f32>, AudioError> {
+    let audio = decode(path)?;
+
+    // Downmixing first means only one channel has to be resampled.
+    let mono = AudioBuffer::new(audio.to_mono(downmix), 1, audio.sample_rate());
+
+    Ok(mono.resample(sample_rate)?.into_samples())
+}
+
+/// Loads an audio file as stereo samples at the given sample rate.
+pub fn load_stereo(
+    path: impl AsRef<Path>,
+    sample_rate: u32,
+) -> Result<Vec<StereoSample>, AudioError> {
+    Ok(decode(path)?.resample(sample_rate)?.to_stereo())
+}
@@ -0,0 +1,72 @@
+use rubato::{
+    Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction,
+};
+
+use crate::audio::AudioError;
+
+const CHUNK_SIZE: usize = 1024;
+
+/// Resamples planar audio, returning exactly `round(frames * ratio)` frames
+/// per channel. The resampler starts centered on the first input frame, so the
+/// output is aligned with the input and only needs to be flushed at the end.
+pub(crate) fn resample_planar(
+    planar: &[Vec<f32>],
+    source_rate: u32,
+    target_rate: u32,
+) -> Result<Vec<Vec<f32>>, AudioError> {
+    let channels = planar.len();
+    let frames = planar.first().map_or(0, |channel| channel.len());
+
+    let ratio = target_rate as f64 / source_rate as f64;
+    let expected_frames = (frames as f64 * ratio).round() as usize;
+
+    let parameters = SincInterpolationParameters {
+        sinc_len: 256,
+        f_cutoff: 0.95,
+        interpolation: SincInterpolationType::Linear,
+        oversampling_factor: 256,
+        window: WindowFunction::BlackmanHarris2,
+    };
+
+    let mut resampler = SincFixedIn::<f32>::new(ratio, 1.0, parameters, CHUNK_SIZE, channels)?;
+
+    let mut output = vec![Vec::with_capacity(expected_frames); channels];
+
+    let mut position = 0;
+    while frames - position >= resampler.input_frames_next() {
+        let chunk_frames = resampler.input_frames_next();
+        let chunk: Vec<&[f32]> = planar
+            .iter()
+            .map(|channel| &channel[position..position + chunk_frames])
+            .collect();
+
+        append(&mut output, resampler.process(&chunk, None)?);
+        position += chunk_frames;
+    }
+
+    if position < frames {
+        let chunk: Vec<&[f32]> = planar.iter().map(|channel| &channel[position..]).collect();
+        append(&mut output, resampler.process_partial(Some(&chunk), None)?);
+    }
+
+    while output[0].len() < expected_frames {
+        append(
+            &mut output,
+            resampler.process_partial::<&[f32]>(None, None)?,
+        );
+    }
+
+    Ok(output
+        .into_iter()
+        .map(|mut channel| {
+            channel.truncate(expected_frames);
+            channel
+        })
+        .collect())
+}
+
+fn append(output: &mut [Vec<f32>], chunk: Vec<Vec<f32>>) {
+    for (channel, samples) in output.iter_mut().zip(chunk) {
+        channel.extend(samples);
+    }
+}
@@ -1,31 +1,21 @@
 use thiserror::Error;
 
-#[derive(Debug, Error)]
-pub enum ConfigureError {
-    #[error("configuration error: {0}")]
-    Configuration(#[from] ConfigurationError),
-}
-
-#[derive(Debug, Error)]
-pub enum ComputeError {
-    #[error("computation error: {0}")]
-    Computation(#[from] CoreComputeError),
-}
+use crate::{
+    algorithm::{ComputeError, ConfigurationError, ParameterError},
+    essentia::CreateAlgorithmError,
+};
 
 #[derive(Debug, Error)]
 pub enum AlgorithmError {
+    #[error("creation error: {0}")]
+    Create(#[from] CreateAlgorithmError),
+
+    #[error("parameter error: {0}")]
+    Parameter(#[from] ParameterError),
+
     #[error("configuration error: {0}")]
-    Configure(#[from] ConfigureError),
+    Configuration(#[from] ConfigurationError),
 
     #[error("computation error: {0}")]
     Compute(#[from] ComputeError),
 }
-
-#[derive(Debug, Error)]
-pub enum EssentiaError {
-    #[error("core error: {0}")]
-    Core(#[from] CoreError),
-
-    #[error("algorithm error: {0}")]
-    Algorithm(#[from] AlgorithmError),
-}
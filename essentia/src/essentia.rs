@@ -1,4 +1,10 @@
-use crate::algorithm::CreateAlgorithm;
+pub use essentia_core::CreateAlgorithmError;
+
+use crate::{
+    AlgorithmError,
+    algorithm::{CreateAlgorithm, DynamicAlgorithm},
+    streaming::{CreateStreamingAlgorithm, Network},
+};
 
 pub struct Essentia {
     pub(crate) inner: essentia_core::Essentia,
@@ -20,4 +26,29 @@ impl Essentia {
     pub fn create<'a, T: CreateAlgorithm<'a>>(&'a self) -> T {
         T::create(self)
     }
+
+    pub fn try_create<'a, T: CreateAlgorithm<'a>>(&'a self) -> Result<T, AlgorithmError> {
+        T::try_create(self)
+    }
+
+    pub fn create_streaming<'a, T: CreateStreamingAlgorithm<'a>>(&'a self) -> T {
+        T::create(self)
+    }
+
+    pub fn try_create_streaming<'a, T: CreateStreamingAlgorithm<'a>>(
+        &'a self,
+    ) -> Result<T, AlgorithmError> {
+        T::try_create(self)
+    }
+
+    pub fn create_dynamic(
+        &self,
+        algorithm_name: &str,
+    ) -> Result<DynamicAlgorithm<'_>, CreateAlgorithmError> {
+        self.inner.create_dynamic_algorithm(algorithm_name)
+    }
+
+    pub fn create_network(&self) -> Network<'_> {
+        Network::new(&self.inner)
+    }
 }
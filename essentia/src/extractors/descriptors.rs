@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use crate::{data_type, pool::Pool};
+
+fn float(pool: &Pool, key: &str) -> Option<f32> {
+    pool.get::<data_type::Float, f32>(key).ok()
+}
+
+fn vector(pool: &Pool, key: &str) -> Option<Vec<f32>> {
+    pool.get::<data_type::VectorFloat, Vec<f32>>(key).ok()
+}
+
+fn string(pool: &Pool, key: &str) -> Option<String> {
+    pool.get::<data_type::String, String>(key).ok()
+}
+
+/// Low-level descriptors, summarized by their mean over all frames.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Lowlevel {
+    pub average_loudness: Option<f32>,
+    pub dynamic_complexity: Option<f32>,
+    pub integrated_loudness: Option<f32>,
+    pub loudness_range: Option<f32>,
+    pub spectral_centroid: Option<f32>,
+    pub spectral_flux: Option<f32>,
+    pub zero_crossing_rate: Option<f32>,
+    pub mfcc: Option<Vec<f32>>,
+}
+
+impl Lowlevel {
+    pub fn from_pool(pool: &Pool) -> Self {
+        Self {
+            average_loudness: float(pool, "lowlevel.average_loudness"),
+            dynamic_complexity: float(pool, "lowlevel.dynamic_complexity"),
+            integrated_loudness: float(pool, "lowlevel.loudness_ebu128.integrated"),
+            loudness_range: float(pool, "lowlevel.loudness_ebu128.loudness_range"),
+            spectral_centroid: float(pool, "lowlevel.spectral_centroid.mean"),
+            spectral_flux: float(pool, "lowlevel.spectral_flux.mean"),
+            zero_crossing_rate: float(pool, "lowlevel.zerocrossingrate.mean"),
+            mfcc: vector(pool, "lowlevel.mfcc.mean"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Rhythm {
+    pub bpm: Option<f32>,
+    pub beats_position: Option<Vec<f32>>,
+    pub beats_count: Option<f32>,
+    pub onset_rate: Option<f32>,
+    pub danceability: Option<f32>,
+}
+
+impl Rhythm {
+    pub fn from_pool(pool: &Pool) -> Self {
+        Self {
+            bpm: float(pool, "rhythm.bpm"),
+            beats_position: vector(pool, "rhythm.beats_position"),
+            beats_count: float(pool, "rhythm.beats_count"),
+            onset_rate: float(pool, "rhythm.onset_rate"),
+            danceability: float(pool, "rhythm.danceability"),
+        }
+    }
+}
+
+/// A key estimate, e.g. `A` `minor`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Key {
+    pub key: String,
+    pub scale: String,
+    pub strength: f32,
+}
+
+impl Key {
+    fn from_pool(pool: &Pool, namespace: &str) -> Option<Self> {
+        Some(Self {
+            key: string(pool, &format!("{namespace}.key"))?,
+            scale: string(pool, &format!("{namespace}.scale"))?,
+            strength: float(pool, &format!("{namespace}.strength"))?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Tonal {
+    pub key_edma: Option<Key>,
+    pub key_krumhansl: Option<Key>,
+    pub key_temperley: Option<Key>,
+    pub chords_key: Option<String>,
+    pub chords_scale: Option<String>,
+    pub tuning_frequency: Option<f32>,
+    pub hpcp: Option<Vec<f32>>,
+}
+
+impl Tonal {
+    pub fn from_pool(pool: &Pool) -> Self {
+        Self {
+            key_edma: Key::from_pool(pool, "tonal.key_edma"),
+            key_krumhansl: Key::from_pool(pool, "tonal.key_krumhansl"),
+            key_temperley: Key::from_pool(pool, "tonal.key_temperley"),
+            chords_key: string(pool, "tonal.chords_key"),
+            chords_scale: string(pool, "tonal.chords_scale"),
+            tuning_frequency: float(pool, "tonal.tuning_frequency"),
+            hpcp: vector(pool, "tonal.hpcp.mean"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Metadata {
+    /// Length of the audio in seconds
+    pub length: Option<f32>,
+    pub sample_rate: Option<f32>,
+    pub bit_rate: Option<f32>,
+    pub codec: Option<String>,
+    /// The tags of the file by lowercase name, e.g. `artist` or `title`
+    pub tags: HashMap<String, Vec<String>>,
+}
+
+impl Metadata {
+    pub fn from_pool(pool: &Pool) -> Self {
+        const TAGS: &str = "metadata.tags.";
+
+        let tags = pool
+            .keys_with_prefix(TAGS)
+            .into_iter()
+            .filter_map(|key| {
+                let values = pool
+                    .get::<data_type::VectorString, Vec<String>>(&key)
+                    .ok()?;
+                Some((key[TAGS.len()..].to_string(), values))
+            })
+            .collect();
+
+        Self {
+            length: float(pool, "metadata.audio_properties.length"),
+            sample_rate: float(pool, "metadata.audio_properties.sample_rate"),
+            bit_rate: float(pool, "metadata.audio_properties.bit_rate"),
+            codec: string(pool, "metadata.audio_properties.codec"),
+            tags,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use essentia_core::algorithm::InputError;
+
+use crate::{
+    algorithm::{DynamicComputeError, ParameterError},
+    essentia::CreateAlgorithmError,
+};
+
+#[derive(Debug, Error)]
+pub enum ExtractorError {
+    #[error("Failed to create extractor: {0}")]
+    Create(#[from] CreateAlgorithmError),
+
+    #[error("Failed to set parameter: {0}")]
+    Parameter(#[from] ParameterError),
+
+    #[error("Failed to set input: {0}")]
+    Input(#[from] InputError),
+
+    #[error("Failed to run extractor: {0}")]
+    Compute(#[from] DynamicComputeError),
+
+    #[error("Path '{}' is not valid UTF-8", .0.display())]
+    InvalidPath(PathBuf),
+
+    #[error("Extractor did not return the '{output}' pool")]
+    MissingOutput { output: String },
+}
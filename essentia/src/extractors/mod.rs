@@ -0,0 +1,186 @@
+//! Essentia's all-in-one `MusicExtractor` and `FreesoundExtractor`, which
+//! analyze an audio file and return the aggregated descriptors together with
+//! the frame-wise values they were computed from.
+//!
+//! The common descriptor groups are available as typed structs, while the
+//! raw pools give access to everything else the extractors compute.
+
+mod descriptors;
+mod error;
+
+use std::path::Path;
+
+use crate::{
+    Essentia,
+    data::Value,
+    pool::{Pool, Statistic},
+};
+
+pub use descriptors::{Key, Lowlevel, Metadata, Rhythm, Tonal};
+pub use error::ExtractorError;
+
+/// The configuration of an extractor. Options that are not set keep the
+/// defaults of the extractor.
+#[derive(Debug, Clone, Default)]
+pub struct Profile {
+    parameters: Vec<(String, Value)>,
+}
+
+impl Profile {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets any parameter of the extractor by its Essentia name.
+    pub fn parameter(mut self, name: &str, value: Value) -> Self {
+        self.parameters.retain(|(existing, _)| existing != name);
+        self.parameters.push((name.to_string(), value));
+        self
+    }
+
+    pub fn analysis_sample_rate(self, sample_rate: f32) -> Self {
+        self.parameter("analysisSampleRate", Value::Float(sample_rate))
+    }
+
+    /// Analyzes the audio from `start` seconds on.
+    pub fn start_time(self, start: f32) -> Self {
+        self.parameter("startTime", Value::Float(start))
+    }
+
+    /// Analyzes the audio up to `end` seconds.
+    pub fn end_time(self, end: f32) -> Self {
+        self.parameter("endTime", Value::Float(end))
+    }
+
+    pub fn lowlevel_frame_size(self, frame_size: i32) -> Self {
+        self.parameter("lowlevelFrameSize", Value::Int(frame_size))
+    }
+
+    pub fn lowlevel_hop_size(self, hop_size: i32) -> Self {
+        self.parameter("lowlevelHopSize", Value::Int(hop_size))
+    }
+
+    pub fn lowlevel_window_type(self, window_type: &str) -> Self {
+        self.parameter("lowlevelWindowType", Value::String(window_type.to_string()))
+    }
+
+    pub fn tonal_frame_size(self, frame_size: i32) -> Self {
+        self.parameter("tonalFrameSize", Value::Int(frame_size))
+    }
+
+    pub fn tonal_hop_size(self, hop_size: i32) -> Self {
+        self.parameter("tonalHopSize", Value::Int(hop_size))
+    }
+
+    /// The beat tracking method, `degara` or `multifeature`.
+    pub fn rhythm_method(self, method: &str) -> Self {
+        self.parameter("rhythmMethod", Value::String(method.to_string()))
+    }
+
+    pub fn rhythm_min_tempo(self, bpm: i32) -> Self {
+        self.parameter("rhythmMinTempo", Value::Int(bpm))
+    }
+
+    pub fn rhythm_max_tempo(self, bpm: i32) -> Self {
+        self.parameter("rhythmMaxTempo", Value::Int(bpm))
+    }
+
+    pub fn lowlevel_stats(self, stats: impl IntoIterator<Item = Statistic>) -> Self {
+        self.parameter("lowlevelStats", stats_value(stats))
+    }
+
+    pub fn rhythm_stats(self, stats: impl IntoIterator<Item = Statistic>) -> Self {
+        self.parameter("rhythmStats", stats_value(stats))
+    }
+
+    pub fn tonal_stats(self, stats: impl IntoIterator<Item = Statistic>) -> Self {
+        self.parameter("tonalStats", stats_value(stats))
+    }
+
+    pub fn mfcc_stats(self, stats: impl IntoIterator<Item = Statistic>) -> Self {
+        self.parameter("mfccStats", stats_value(stats))
+    }
+
+    pub fn gfcc_stats(self, stats: impl IntoIterator<Item = Statistic>) -> Self {
+        self.parameter("gfccStats", stats_value(stats))
+    }
+
+    /// Reads the remaining options from an Essentia profile file. Only
+    /// supported by `MusicExtractor`.
+    pub fn profile_file(self, path: &str) -> Self {
+        self.parameter("profile", Value::String(path.to_string()))
+    }
+}
+
+fn stats_value(stats: impl IntoIterator<Item = Statistic>) -> Value {
+    Value::VectorString(
+        stats
+            .into_iter()
+            .map(|stat| stat.as_str().to_string())
+            .collect(),
+    )
+}
+
+/// The result of an extractor.
+#[derive(Debug, Clone)]
+pub struct Analysis {
+    pub lowlevel: Lowlevel,
+    pub rhythm: Rhythm,
+    pub tonal: Tonal,
+    pub metadata: Metadata,
+    /// All aggregated descriptors
+    pub pool: Pool,
+    /// The frame-wise values of the descriptors
+    pub frames: Pool,
+}
+
+impl Analysis {
+    pub fn from_pools(pool: Pool, frames: Pool) -> Self {
+        Self {
+            lowlevel: Lowlevel::from_pool(&pool),
+            rhythm: Rhythm::from_pool(&pool),
+            tonal: Tonal::from_pool(&pool),
+            metadata: Metadata::from_pool(&pool),
+            pool,
+            frames,
+        }
+    }
+}
+
+/// Analyzes a music file with `MusicExtractor`.
+pub fn music(path: impl AsRef<Path>, profile: &Profile) -> Result<Analysis, ExtractorError> {
+    extract("MusicExtractor", path.as_ref(), profile)
+}
+
+/// Analyzes a sound file with `FreesoundExtractor`, which is tailored to
+/// short sounds and effects rather than music.
+pub fn freesound(path: impl AsRef<Path>, profile: &Profile) -> Result<Analysis, ExtractorError> {
+    extract("FreesoundExtractor", path.as_ref(), profile)
+}
+
+fn extract(algorithm: &str, path: &Path, profile: &Profile) -> Result<Analysis, ExtractorError> {
+    let essentia = Essentia::new();
+    let mut extractor = essentia.create_dynamic(algorithm)?;
+
+    for (name, value) in &profile.parameters {
+        extractor.set_parameter(name, value.clone())?;
+    }
+
+    let filename = path
+        .to_str()
+        .ok_or_else(|| ExtractorError::InvalidPath(path.to_path_buf()))?;
+    extractor.set_input("filename", Value::String(filename.to_string()))?;
+
+    let mut outputs = extractor.compute()?;
+    let mut take_pool = |output: &str| match outputs.remove(output) {
+        Some(Value::Pool(pool)) => Ok(pool),
+        _ => Err(ExtractorError::MissingOutput {
+            output: output.to_string(),
+        }),
+    };
+
+    let pool = take_pool("results")?;
+    let frames = take_pool("resultsFrames")?;
+
+    Ok(Analysis::from_pools(pool, frames))
+}
@@ -0,0 +1,247 @@
+//! Iteration over the frames of a signal, covering the `FrameCutter` ->
+//! `Windowing` -> `Spectrum` chain that most frame-wise features start with.
+//!
+//! ```ignore
+//! let essentia = Essentia::new();
+//! let config = FrameConfig::default().frame_size(2048).hop_size(1024);
+//!
+//! for spectrum in FrameIter::new(&essentia, &signal, config)?.spectra()? {
+//!     let spectrum = spectrum?;
+//! }
+//! ```
+
+use essentia_core::algorithm::{
+    Algorithm, ComputeError as CoreComputeError, ConfigurationError as CoreConfigurationError,
+};
+
+use crate::{
+    AlgorithmError, Configured, Essentia,
+    algorithm::{
+        ComputeError, ConfigurationError,
+        spectral::Spectrum,
+        standard::{Windowing, WindowingType},
+    },
+    data::{GetFromDataContainer, data_type},
+};
+
+/// The framing and windowing options of a [`FrameIter`], named after the
+/// parameters of `FrameCutter` and `Windowing`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameConfig {
+    frame_size: usize,
+    hop_size: usize,
+    window: Option<WindowingType>,
+    zero_padding: usize,
+    start_from_zero: bool,
+    last_frame_to_end_of_file: bool,
+    valid_frame_threshold_ratio: f32,
+}
+
+impl Default for FrameConfig {
+    fn default() -> Self {
+        Self {
+            frame_size: 1024,
+            hop_size: 512,
+            window: Some(WindowingType::Hann),
+            zero_padding: 0,
+            start_from_zero: false,
+            last_frame_to_end_of_file: false,
+            valid_frame_threshold_ratio: 0.0,
+        }
+    }
+}
+
+impl FrameConfig {
+    pub fn frame_size(mut self, frame_size: usize) -> Self {
+        self.frame_size = frame_size;
+        self
+    }
+
+    pub fn hop_size(mut self, hop_size: usize) -> Self {
+        self.hop_size = hop_size;
+        self
+    }
+
+    pub fn window(mut self, window: WindowingType) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Yields the frames as they are cut from the signal, without applying
+    /// a window.
+    pub fn no_window(mut self) -> Self {
+        self.window = None;
+        self
+    }
+
+    /// Number of zeros appended to each windowed frame, which increases the
+    /// resolution of the spectrum.
+    pub fn zero_padding(mut self, zero_padding: usize) -> Self {
+        self.zero_padding = zero_padding;
+        self
+    }
+
+    /// Starts the first frame at the beginning of the signal instead of
+    /// centering it on the first sample.
+    pub fn start_from_zero(mut self, start_from_zero: bool) -> Self {
+        self.start_from_zero = start_from_zero;
+        self
+    }
+
+    pub fn last_frame_to_end_of_file(mut self, last_frame_to_end_of_file: bool) -> Self {
+        self.last_frame_to_end_of_file = last_frame_to_end_of_file;
+        self
+    }
+
+    /// Minimum ratio of signal in the last frames below which they are
+    /// dropped rather than zero-padded.
+    pub fn valid_frame_threshold_ratio(mut self, valid_frame_threshold_ratio: f32) -> Self {
+        self.valid_frame_threshold_ratio = valid_frame_threshold_ratio;
+        self
+    }
+
+    /// The size of the frames yielded by a [`FrameIter`], including the zero
+    /// padding of windowed frames.
+    pub fn output_size(&self) -> usize {
+        match self.window {
+            Some(_) => self.frame_size + self.zero_padding,
+            None => self.frame_size,
+        }
+    }
+}
+
+/// Iterator over the windowed frames of a signal.
+///
+/// The signal is copied into `FrameCutter` once, and the configured
+/// algorithms are reused for every frame. The iterator ends when
+/// `FrameCutter` returns an empty frame.
+pub struct FrameIter<'a> {
+    essentia: &'a Essentia,
+    config: FrameConfig,
+    frame_cutter: Algorithm<'a, Configured>,
+    windowing: Option<Windowing<'a, Configured>>,
+    finished: bool,
+}
+
+impl<'a> FrameIter<'a> {
+    pub fn new(
+        essentia: &'a Essentia,
+        signal: &[f32],
+        config: FrameConfig,
+    ) -> Result<Self, AlgorithmError> {
+        let mut frame_cutter = essentia
+            .inner
+            .create_algorithm("FrameCutter")?
+            .parameter::<data_type::Int>("frameSize", config.frame_size as i32)?
+            .parameter::<data_type::Int>("hopSize", config.hop_size as i32)?
+            .parameter::<data_type::Bool>("startFromZero", config.start_from_zero)?
+            .parameter::<data_type::Bool>("lastFrameToEndOfFile", config.last_frame_to_end_of_file)?
+            .parameter::<data_type::Float>(
+                "validFrameThresholdRatio",
+                config.valid_frame_threshold_ratio,
+            )?
+            .configure()
+            .map_err(|CoreConfigurationError::Internal(exception)| {
+                ConfigurationError::Internal(exception)
+            })?;
+
+        frame_cutter
+            .set_input::<data_type::VectorFloat>("signal", signal)
+            .expect("FrameCutter should have a VectorFloat signal input");
+
+        let windowing = match config.window {
+            Some(window) => Some(
+                essentia
+                    .try_create::<Windowing>()?
+                    .try_type(window)?
+                    .try_size(config.frame_size as i32)?
+                    .try_zero_padding(config.zero_padding as i32)?
+                    .configure()?,
+            ),
+            None => None,
+        };
+
+        Ok(Self {
+            essentia,
+            config,
+            frame_cutter,
+            windowing,
+            finished: false,
+        })
+    }
+
+    pub fn config(&self) -> &FrameConfig {
+        &self.config
+    }
+
+    /// Turns the iterator into one over the magnitude spectra of the frames.
+    pub fn spectra(self) -> Result<SpectrumIter<'a>, AlgorithmError> {
+        let spectrum = self
+            .essentia
+            .try_create::<Spectrum>()?
+            .try_size(self.config.output_size() as i32)?
+            .configure()?;
+
+        Ok(SpectrumIter {
+            frames: self,
+            spectrum,
+        })
+    }
+
+    fn next_frame(&mut self) -> Result<Option<Vec<f32>>, ComputeError> {
+        let frame: Vec<f32> = self
+            .frame_cutter
+            .compute()
+            .map_err(|CoreComputeError::Compute(exception)| ComputeError::Compute(exception))?
+            .output::<data_type::VectorFloat>("frame")
+            .expect("FrameCutter should have a VectorFloat frame output")
+            .get();
+
+        if frame.is_empty() {
+            return Ok(None);
+        }
+
+        match &mut self.windowing {
+            Some(windowing) => Ok(Some(windowing.compute(frame.as_slice())?.frame().get())),
+            None => Ok(Some(frame)),
+        }
+    }
+}
+
+impl Iterator for FrameIter<'_> {
+    type Item = Result<Vec<f32>, ComputeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let frame = self.next_frame().transpose();
+        self.finished = !matches!(frame, Some(Ok(_)));
+        frame
+    }
+}
+
+/// Iterator over the magnitude spectra of the frames of a signal, created by
+/// [`FrameIter::spectra`].
+pub struct SpectrumIter<'a> {
+    frames: FrameIter<'a>,
+    spectrum: Spectrum<'a, Configured>,
+}
+
+impl Iterator for SpectrumIter<'_> {
+    type Item = Result<Vec<f32>, ComputeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = match self.frames.next()? {
+            Ok(frame) => frame,
+            Err(error) => return Some(Err(error)),
+        };
+
+        Some(
+            self.spectrum
+                .compute(frame.as_slice())
+                .map(|result| result.spectrum().get()),
+        )
+    }
+}
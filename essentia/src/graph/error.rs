@@ -0,0 +1,110 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use essentia_core::algorithm::{ConfigurationError, InputError, ResetError};
+
+use crate::{
+    algorithm::{DynamicComputeError, ParameterError},
+    data::DataType,
+    essentia::CreateAlgorithmError,
+};
+
+#[derive(Debug, Error)]
+pub enum GraphError {
+    #[error("Failed to read graph description: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse TOML graph description: {0}")]
+    Toml(#[from] toml::de::Error),
+
+    #[error("Failed to parse YAML graph description: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
+    #[error("Unsupported graph description format: '{}'", .0.display())]
+    UnsupportedFormat(PathBuf),
+
+    #[error("Invalid node name '{node}': names must not contain '.'")]
+    InvalidNodeName { node: String },
+
+    #[error("Failed to create node '{node}': {source}")]
+    Create {
+        node: String,
+        #[source]
+        source: CreateAlgorithmError,
+    },
+
+    #[error("Invalid value for parameter '{parameter}' of node '{node}': expected {expected}")]
+    InvalidParameterValue {
+        node: String,
+        parameter: String,
+        expected: DataType,
+    },
+
+    #[error("Failed to set parameter of node '{node}': {source}")]
+    Parameter {
+        node: String,
+        #[source]
+        source: ParameterError,
+    },
+
+    #[error("Failed to configure node '{node}': {source}")]
+    Configuration {
+        node: String,
+        #[source]
+        source: ConfigurationError,
+    },
+
+    #[error("Node '{node}' has no input '{input}'")]
+    UnknownInput { node: String, input: String },
+
+    #[error("Input '{input}' of node '{node}' is not connected")]
+    UnconnectedInput { node: String, input: String },
+
+    #[error("'{reference}' is neither a graph input nor a node output")]
+    UnknownReference { reference: String },
+
+    #[error(
+        "Type mismatch for input '{input}' of node '{node}': expected {expected}, found {actual}"
+    )]
+    TypeMismatch {
+        node: String,
+        input: String,
+        expected: DataType,
+        actual: DataType,
+    },
+
+    #[error("The graph contains a cycle through node '{node}'")]
+    Cycle { node: String },
+
+    #[error("Missing graph input '{input}'")]
+    MissingInput { input: String },
+
+    #[error("Type mismatch for graph input '{input}': expected {expected}, found {actual}")]
+    InputTypeMismatch {
+        input: String,
+        expected: DataType,
+        actual: DataType,
+    },
+
+    #[error("Failed to set input of node '{node}': {source}")]
+    Input {
+        node: String,
+        #[source]
+        source: InputError,
+    },
+
+    #[error("Failed to compute node '{node}': {source}")]
+    Compute {
+        node: String,
+        #[source]
+        source: DynamicComputeError,
+    },
+
+    #[error("Failed to reset node '{node}': {source}")]
+    Reset {
+        node: String,
+        #[source]
+        source: ResetError,
+    },
+}
@@ -0,0 +1,339 @@
+//! Declarative analysis graphs, loaded from TOML or YAML descriptions
+//! instead of being wired up by hand.
+//!
+//! ```toml
+//! [inputs]
+//! frame = "VectorFloat"
+//!
+//! [nodes.window]
+//! algorithm = "Windowing"
+//! parameters = { type = "hann" }
+//! inputs = { frame = "frame" }
+//!
+//! [nodes.spectrum]
+//! algorithm = "Spectrum"
+//! inputs = { frame = "window.frame" }
+//!
+//! [outputs]
+//! spectrum = "spectrum.spectrum"
+//! ```
+//!
+//! Every node is a standard algorithm. The parameters, the connections and
+//! their types are checked against the introspection of the algorithms when
+//! the [`Graph`] is built, so a description that loads successfully can only
+//! fail while running because of the processed data.
+
+mod error;
+mod parameter;
+mod spec;
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use crate::{
+    Essentia,
+    algorithm::{DynamicAlgorithm, ParameterError},
+    data::{DataType, Value},
+};
+
+pub use error::GraphError;
+pub use spec::{GraphSpec, NodeSpec, ParameterValue};
+
+struct Node<'a> {
+    name: String,
+    algorithm: DynamicAlgorithm<'a>,
+    inputs: BTreeMap<String, String>,
+}
+
+/// A validated [`GraphSpec`] with its algorithms created and configured,
+/// ready to be run once per frame.
+pub struct Graph<'a> {
+    inputs: BTreeMap<String, DataType>,
+    nodes: Vec<Node<'a>>,
+    outputs: BTreeMap<String, String>,
+}
+
+impl<'a> Graph<'a> {
+    pub fn new(essentia: &'a Essentia, spec: &GraphSpec) -> Result<Self, GraphError> {
+        let mut types: HashMap<String, DataType> = spec.inputs.clone().into_iter().collect();
+        let mut nodes = BTreeMap::new();
+
+        for (name, node_spec) in &spec.nodes {
+            let algorithm = create_node(essentia, name, node_spec)?;
+
+            for output in algorithm.introspection().outputs() {
+                types.insert(
+                    format!("{}.{}", name, output.name()),
+                    output.input_output_type(),
+                );
+            }
+
+            nodes.insert(name.clone(), algorithm);
+        }
+
+        for (name, node_spec) in &spec.nodes {
+            validate_connections(name, &nodes[name], node_spec, &types)?;
+        }
+
+        for reference in spec.outputs.values() {
+            if !types.contains_key(reference) {
+                return Err(GraphError::UnknownReference {
+                    reference: reference.clone(),
+                });
+            }
+        }
+
+        let nodes = sort_nodes(nodes, spec)?;
+
+        Ok(Self {
+            inputs: spec.inputs.clone(),
+            nodes,
+            outputs: spec.outputs.clone(),
+        })
+    }
+
+    pub fn from_toml(essentia: &'a Essentia, toml: &str) -> Result<Self, GraphError> {
+        Self::new(essentia, &GraphSpec::from_toml(toml)?)
+    }
+
+    pub fn from_yaml(essentia: &'a Essentia, yaml: &str) -> Result<Self, GraphError> {
+        Self::new(essentia, &GraphSpec::from_yaml(yaml)?)
+    }
+
+    pub fn from_file(
+        essentia: &'a Essentia,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, GraphError> {
+        Self::new(essentia, &GraphSpec::from_file(path)?)
+    }
+
+    /// Returns the node names in the order they are computed.
+    pub fn node_names(&self) -> impl Iterator<Item = &str> {
+        self.nodes.iter().map(|node| node.name.as_str())
+    }
+
+    /// Runs every node once on the given graph inputs and returns the graph
+    /// outputs.
+    pub fn run(
+        &mut self,
+        inputs: &HashMap<String, Value>,
+    ) -> Result<HashMap<String, Value>, GraphError> {
+        let mut values = HashMap::new();
+
+        for (name, expected) in &self.inputs {
+            let value = inputs.get(name).ok_or_else(|| GraphError::MissingInput {
+                input: name.clone(),
+            })?;
+
+            if value.data_type() != *expected {
+                return Err(GraphError::InputTypeMismatch {
+                    input: name.clone(),
+                    expected: *expected,
+                    actual: value.data_type(),
+                });
+            }
+
+            values.insert(name.clone(), value.clone());
+        }
+
+        for node in &mut self.nodes {
+            for (input, reference) in &node.inputs {
+                node.algorithm
+                    .set_input(input, values[reference].clone())
+                    .map_err(|source| GraphError::Input {
+                        node: node.name.clone(),
+                        source,
+                    })?;
+            }
+
+            let outputs = node
+                .algorithm
+                .compute()
+                .map_err(|source| GraphError::Compute {
+                    node: node.name.clone(),
+                    source,
+                })?;
+
+            for (output, value) in outputs {
+                values.insert(format!("{}.{}", node.name, output), value);
+            }
+        }
+
+        Ok(self
+            .outputs
+            .iter()
+            .map(|(name, reference)| (name.clone(), values[reference].clone()))
+            .collect())
+    }
+
+    /// Runs the graph once per frame, e.g. over the frames of a signal.
+    pub fn run_frames<I>(&mut self, frames: I) -> Result<Vec<HashMap<String, Value>>, GraphError>
+    where
+        I: IntoIterator<Item = HashMap<String, Value>>,
+    {
+        frames.into_iter().map(|frame| self.run(&frame)).collect()
+    }
+
+    /// Resets the state of every algorithm, e.g. before processing another
+    /// signal.
+    pub fn reset(&mut self) -> Result<(), GraphError> {
+        for node in &mut self.nodes {
+            node.algorithm.reset().map_err(|source| GraphError::Reset {
+                node: node.name.clone(),
+                source,
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+fn create_node<'a>(
+    essentia: &'a Essentia,
+    name: &str,
+    spec: &NodeSpec,
+) -> Result<DynamicAlgorithm<'a>, GraphError> {
+    if name.contains('.') {
+        return Err(GraphError::InvalidNodeName {
+            node: name.to_string(),
+        });
+    }
+
+    let mut algorithm =
+        essentia
+            .create_dynamic(&spec.algorithm)
+            .map_err(|source| GraphError::Create {
+                node: name.to_string(),
+                source,
+            })?;
+
+    for (parameter, value) in &spec.parameters {
+        let parameter_error = |source| GraphError::Parameter {
+            node: name.to_string(),
+            source,
+        };
+
+        let parameter_type = algorithm
+            .introspection()
+            .get_parameter(parameter)
+            .map(|info| info.parameter_type())
+            .ok_or_else(|| {
+                parameter_error(ParameterError::ParameterNotFound {
+                    parameter: parameter.clone(),
+                })
+            })?;
+
+        let value =
+            value
+                .to_value(parameter_type)
+                .ok_or_else(|| GraphError::InvalidParameterValue {
+                    node: name.to_string(),
+                    parameter: parameter.clone(),
+                    expected: parameter_type,
+                })?;
+
+        algorithm
+            .set_parameter(parameter, value)
+            .map_err(parameter_error)?;
+    }
+
+    algorithm
+        .configure()
+        .map_err(|source| GraphError::Configuration {
+            node: name.to_string(),
+            source,
+        })?;
+
+    Ok(algorithm)
+}
+
+fn validate_connections(
+    name: &str,
+    algorithm: &DynamicAlgorithm<'_>,
+    spec: &NodeSpec,
+    types: &HashMap<String, DataType>,
+) -> Result<(), GraphError> {
+    let introspection = algorithm.introspection();
+
+    for (input, reference) in &spec.inputs {
+        let input_info =
+            introspection
+                .get_input(input)
+                .ok_or_else(|| GraphError::UnknownInput {
+                    node: name.to_string(),
+                    input: input.clone(),
+                })?;
+
+        let actual = *types
+            .get(reference)
+            .ok_or_else(|| GraphError::UnknownReference {
+                reference: reference.clone(),
+            })?;
+        let expected = input_info.input_output_type();
+
+        if actual != expected {
+            return Err(GraphError::TypeMismatch {
+                node: name.to_string(),
+                input: input.clone(),
+                expected,
+                actual,
+            });
+        }
+    }
+
+    if let Some(input) = introspection
+        .inputs()
+        .find(|input| !spec.inputs.contains_key(input.name()))
+    {
+        return Err(GraphError::UnconnectedInput {
+            node: name.to_string(),
+            input: input.name().to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Orders the nodes so that every node comes after the nodes it reads from.
+fn sort_nodes<'a>(
+    mut algorithms: BTreeMap<String, DynamicAlgorithm<'a>>,
+    spec: &GraphSpec,
+) -> Result<Vec<Node<'a>>, GraphError> {
+    let dependencies = |node_spec: &NodeSpec| -> HashSet<String> {
+        node_spec
+            .inputs
+            .values()
+            .filter_map(|reference| reference.split_once('.'))
+            .map(|(node, _)| node.to_string())
+            .collect()
+    };
+
+    let mut remaining: BTreeMap<&String, HashSet<String>> = spec
+        .nodes
+        .iter()
+        .map(|(name, node_spec)| (name, dependencies(node_spec)))
+        .collect();
+    let mut nodes = Vec::with_capacity(remaining.len());
+
+    while let Some((&name, _)) = remaining.first_key_value() {
+        let ready = remaining
+            .iter()
+            .find(|(_, dependencies)| {
+                dependencies
+                    .iter()
+                    .all(|dependency| !remaining.contains_key(dependency))
+            })
+            .map(|(&name, _)| name)
+            .ok_or_else(|| GraphError::Cycle { node: name.clone() })?;
+
+        remaining.remove(ready);
+        nodes.push(Node {
+            name: ready.clone(),
+            algorithm: algorithms
+                .remove(ready)
+                .expect("every node spec has an algorithm"),
+            inputs: spec.nodes[ready].inputs.clone(),
+        });
+    }
+
+    Ok(nodes)
+}
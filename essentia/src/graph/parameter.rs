@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use ndarray::Array2;
+
+use crate::{
+    data::{DataType, Value},
+    graph::ParameterValue,
+};
+
+impl ParameterValue {
+    /// Converts the value to the type of the parameter it is given for, or
+    /// returns `None` if it cannot represent that type.
+    pub fn to_value(&self, data_type: DataType) -> Option<Value> {
+        let value = match data_type {
+            DataType::Bool => Value::Bool(self.as_bool()?),
+            DataType::String => Value::String(self.as_str()?.to_string()),
+            DataType::Float => Value::Float(self.as_f64()? as f32),
+            DataType::Int => Value::Int(self.as_i64()?.try_into().ok()?),
+            DataType::UnsignedInt => Value::UnsignedInt(self.as_i64()?.try_into().ok()?),
+            DataType::Long => Value::Long(self.as_i64()?),
+            DataType::Complex => {
+                let [re, im] = self.as_list()? else {
+                    return None;
+                };
+                Value::Complex(num::Complex::new(re.as_f64()? as f32, im.as_f64()? as f32))
+            }
+            DataType::VectorBool => Value::VectorBool(self.map_list(Self::as_bool)?),
+            DataType::VectorInt => {
+                Value::VectorInt(self.map_list(|value| value.as_i64()?.try_into().ok())?)
+            }
+            DataType::VectorString => {
+                Value::VectorString(self.map_list(|value| Some(value.as_str()?.to_string()))?)
+            }
+            DataType::VectorFloat => Value::VectorFloat(self.map_list(Self::as_f32)?),
+            DataType::VectorVectorFloat => {
+                Value::VectorVectorFloat(self.map_list(|row| row.map_list(Self::as_f32))?)
+            }
+            DataType::MatrixFloat => {
+                let rows = self.map_list(|row| row.map_list(Self::as_f32))?;
+                let columns = rows.first().map_or(0, Vec::len);
+                let data: Vec<f32> = rows.iter().flatten().copied().collect();
+                Value::MatrixFloat(Array2::from_shape_vec((rows.len(), columns), data).ok()?)
+            }
+            DataType::VectorVectorString => Value::VectorVectorString(
+                self.map_list(|row| row.map_list(|value| Some(value.as_str()?.to_string())))?,
+            ),
+            DataType::MapVectorFloat => {
+                Value::MapVectorFloat(self.map_map(|value| value.map_list(Self::as_f32))?)
+            }
+            DataType::MapVectorString => Value::MapVectorString(
+                self.map_map(|value| value.map_list(|value| Some(value.as_str()?.to_string())))?,
+            ),
+            DataType::MapVectorInt => Value::MapVectorInt(
+                self.map_map(|value| value.map_list(|value| value.as_i64()?.try_into().ok()))?,
+            ),
+            DataType::MapFloat => Value::MapFloat(self.map_map(Self::as_f32)?),
+            _ => return None,
+        };
+
+        Some(value)
+    }
+
+    fn as_bool(&self) -> Option<bool> {
+        match self {
+            ParameterValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn as_i64(&self) -> Option<i64> {
+        match self {
+            ParameterValue::Int(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            ParameterValue::Int(value) => Some(*value as f64),
+            ParameterValue::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    fn as_f32(&self) -> Option<f32> {
+        self.as_f64().map(|value| value as f32)
+    }
+
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            ParameterValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn as_list(&self) -> Option<&[ParameterValue]> {
+        match self {
+            ParameterValue::List(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    fn map_list<T>(&self, convert: impl Fn(&ParameterValue) -> Option<T>) -> Option<Vec<T>> {
+        self.as_list()?.iter().map(convert).collect()
+    }
+
+    fn map_map<T>(
+        &self,
+        convert: impl Fn(&ParameterValue) -> Option<T>,
+    ) -> Option<HashMap<String, T>> {
+        let ParameterValue::Map(values) = self else {
+            return None;
+        };
+
+        values
+            .iter()
+            .map(|(key, value)| Some((key.clone(), convert(value)?)))
+            .collect()
+    }
+}
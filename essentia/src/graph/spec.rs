@@ -0,0 +1,75 @@
+use std::{collections::BTreeMap, path::Path};
+
+use serde::Deserialize;
+
+use crate::{data::DataType, graph::GraphError};
+
+/// The declarative description of a [`Graph`](crate::graph::Graph).
+///
+/// Node inputs and graph outputs refer to values by name: a plain name such
+/// as `frame` is an input of the graph, while `window.frame` is the output
+/// `frame` of the node `window`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GraphSpec {
+    /// The inputs fed to the graph on every run, with their types
+    #[serde(default)]
+    pub inputs: BTreeMap<String, DataType>,
+
+    #[serde(default)]
+    pub nodes: BTreeMap<String, NodeSpec>,
+
+    /// The values returned by every run, by the name they are returned under
+    #[serde(default)]
+    pub outputs: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NodeSpec {
+    /// The name of the standard Essentia algorithm, e.g. `Windowing`
+    pub algorithm: String,
+
+    #[serde(default)]
+    pub parameters: BTreeMap<String, ParameterValue>,
+
+    /// The value connected to each input of the algorithm
+    #[serde(default)]
+    pub inputs: BTreeMap<String, String>,
+}
+
+/// A parameter as written in the description, which is converted to the
+/// type the algorithm expects when the graph is built.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum ParameterValue {
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    String(String),
+    List(Vec<ParameterValue>),
+    Map(BTreeMap<String, ParameterValue>),
+}
+
+impl GraphSpec {
+    pub fn from_toml(toml: &str) -> Result<Self, GraphError> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    pub fn from_yaml(yaml: &str) -> Result<Self, GraphError> {
+        Ok(serde_yaml::from_str(yaml)?)
+    }
+
+    /// Reads a description, choosing the format by the `.toml`, `.yaml` or
+    /// `.yml` extension of the file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, GraphError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::from_toml(&content),
+            Some("yaml" | "yml") => Self::from_yaml(&content),
+            _ => Err(GraphError::UnsupportedFormat(path.to_path_buf())),
+        }
+    }
+}
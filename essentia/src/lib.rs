@@ -1,14 +1,30 @@
 pub mod algorithm;
+#[cfg(feature = "audio")]
+pub mod audio;
+mod error;
 pub mod essentia;
+#[cfg(all(
+    feature = "ffmpeg",
+    feature = "samplerate",
+    feature = "taglib",
+    feature = "yaml"
+))]
+pub mod extractors;
+pub mod frames;
+#[cfg(feature = "graph")]
+pub mod graph;
+pub mod rhythm;
+pub mod streaming;
 pub use essentia_core::{data, parameter_map, pool};
 
 pub use data::{
     ConversionError, DataContainer, DataType, GetFromDataContainer, InputOutputData,
-    IntoDataContainer, ParameterData, PoolData, TryGetFromDataContainer, TryIntoDataContainer,
-    data_type,
+    IntoDataContainer, ParameterData, PoolData, PoolSeriesData, TryGetFromDataContainer,
+    TryIntoDataContainer, Value, data_type,
 };
 
 pub use algorithm::{Configured, Initialized};
+pub use error::AlgorithmError;
 pub use essentia::Essentia;
 
-pub use pool::{Pool, PoolError};
+pub use pool::{AggregationSpec, MergeType, Pool, PoolError, Statistic};
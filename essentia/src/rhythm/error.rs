@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::AlgorithmError;
+
+use super::TempoMethod;
+
+#[derive(Debug, Error)]
+pub enum RhythmError {
+    #[error("Failed to run rhythm algorithm: {0}")]
+    Algorithm(#[from] AlgorithmError),
+
+    #[error(
+        "{} requires audio sampled at {expected} Hz, got {sample_rate} Hz",
+        .method.algorithm_name()
+    )]
+    UnsupportedSampleRate {
+        method: TempoMethod,
+        expected: u32,
+        sample_rate: u32,
+    },
+}
@@ -0,0 +1,239 @@
+//! Tempo estimation and beat tracking behind a single entry point, so the
+//! differently named outputs of Essentia's rhythm algorithms do not leak into
+//! calling code.
+
+mod error;
+
+use crate::{
+    AlgorithmError, Essentia,
+    algorithm::rhythm::{
+        BeatTrackerMultiFeature, BpmHistogramDescriptors, PercivalBpmEstimator,
+        RhythmExtractor2013, RhythmExtractor2013Method,
+    },
+};
+
+pub use error::RhythmError;
+
+/// Sample rate the beat trackers of Essentia are tuned to.
+const BEAT_TRACKER_SAMPLE_RATE: u32 = 44100;
+
+/// The algorithm [`analyze_tempo`] uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TempoMethod {
+    /// `RhythmExtractor2013` with the multi-feature beat tracker, the most
+    /// accurate and slowest method
+    #[default]
+    MultiFeature,
+    /// `RhythmExtractor2013` with the faster Degara beat tracker
+    Degara,
+    /// `BeatTrackerMultiFeature`, which tracks beats without estimating
+    /// alternative tempos
+    BeatTracker,
+    /// `PercivalBpmEstimator`, which only estimates the tempo but supports
+    /// any sample rate
+    Percival,
+}
+
+impl TempoMethod {
+    fn algorithm_name(&self) -> &'static str {
+        match self {
+            TempoMethod::MultiFeature | TempoMethod::Degara => "RhythmExtractor2013",
+            TempoMethod::BeatTracker => "BeatTrackerMultiFeature",
+            TempoMethod::Percival => "PercivalBpmEstimator",
+        }
+    }
+
+    fn requires_beat_tracker_sample_rate(&self) -> bool {
+        !matches!(self, TempoMethod::Percival)
+    }
+}
+
+/// One of the two highest peaks of a [`BpmHistogram`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HistogramPeak {
+    pub bpm: f32,
+    pub weight: f32,
+    pub spread: f32,
+}
+
+/// The distribution of the tempos implied by the beat intervals, as computed
+/// by `BpmHistogramDescriptors`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BpmHistogram {
+    /// Weight of each tempo, indexed by BPM
+    pub histogram: Vec<f32>,
+    pub first_peak: HistogramPeak,
+    pub second_peak: HistogramPeak,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TempoAnalysis {
+    pub bpm: f32,
+    /// Confidence of the beat tracking, which only the multi-feature
+    /// trackers report. `RhythmExtractor2013` reports zero for the Degara
+    /// tracker, which is returned as `None`.
+    pub confidence: Option<f32>,
+    /// Beat positions in seconds
+    pub beats: Vec<f32>,
+    /// Time between consecutive beats in seconds
+    pub beat_intervals: Vec<f32>,
+    /// Alternative tempo estimates, only reported by `RhythmExtractor2013`
+    pub bpm_estimates: Vec<f32>,
+    /// Only available if beats were tracked
+    pub histogram: Option<BpmHistogram>,
+}
+
+/// Estimates the tempo of a mono signal and, except for
+/// [`TempoMethod::Percival`], tracks its beats.
+///
+/// The beat trackers expect audio sampled at 44100 Hz, other rates have to be
+/// resampled first.
+pub fn analyze_tempo(
+    essentia: &Essentia,
+    signal: &[f32],
+    sample_rate: u32,
+    method: TempoMethod,
+) -> Result<TempoAnalysis, RhythmError> {
+    if method.requires_beat_tracker_sample_rate() && sample_rate != BEAT_TRACKER_SAMPLE_RATE {
+        return Err(RhythmError::UnsupportedSampleRate {
+            method,
+            expected: BEAT_TRACKER_SAMPLE_RATE,
+            sample_rate,
+        });
+    }
+
+    let analysis = match method {
+        TempoMethod::MultiFeature => {
+            rhythm_extractor(essentia, signal, RhythmExtractor2013Method::Multifeature)?
+        }
+        TempoMethod::Degara => {
+            rhythm_extractor(essentia, signal, RhythmExtractor2013Method::Degara)?
+        }
+        TempoMethod::BeatTracker => beat_tracker(essentia, signal)?,
+        TempoMethod::Percival => percival(essentia, signal, sample_rate)?,
+    };
+
+    Ok(TempoAnalysis {
+        histogram: bpm_histogram(essentia, &analysis.beat_intervals)?,
+        ..analysis
+    })
+}
+
+fn rhythm_extractor(
+    essentia: &Essentia,
+    signal: &[f32],
+    method: RhythmExtractor2013Method,
+) -> Result<TempoAnalysis, AlgorithmError> {
+    let output = essentia
+        .try_create::<RhythmExtractor2013>()?
+        .try_method(method)?
+        .configure()?
+        .compute(signal)?
+        .into_owned();
+
+    Ok(TempoAnalysis {
+        bpm: output.bpm,
+        confidence: match method {
+            RhythmExtractor2013Method::Multifeature => Some(output.confidence),
+            RhythmExtractor2013Method::Degara => None,
+        },
+        beats: output.ticks,
+        beat_intervals: output.bpm_intervals,
+        bpm_estimates: output.estimates,
+        histogram: None,
+    })
+}
+
+fn beat_tracker(essentia: &Essentia, signal: &[f32]) -> Result<TempoAnalysis, AlgorithmError> {
+    let output = essentia
+        .try_create::<BeatTrackerMultiFeature>()?
+        .configure()?
+        .compute(signal)?
+        .into_owned();
+
+    let beat_intervals = intervals(&output.ticks);
+
+    Ok(TempoAnalysis {
+        bpm: bpm_from_intervals(&beat_intervals),
+        confidence: Some(output.confidence),
+        beats: output.ticks,
+        beat_intervals,
+        bpm_estimates: Vec::new(),
+        histogram: None,
+    })
+}
+
+fn percival(
+    essentia: &Essentia,
+    signal: &[f32],
+    sample_rate: u32,
+) -> Result<TempoAnalysis, AlgorithmError> {
+    let output = essentia
+        .try_create::<PercivalBpmEstimator>()?
+        .try_sample_rate(sample_rate as i32)?
+        .configure()?
+        .compute(signal)?
+        .into_owned();
+
+    Ok(TempoAnalysis {
+        bpm: output.bpm,
+        confidence: None,
+        beats: Vec::new(),
+        beat_intervals: Vec::new(),
+        bpm_estimates: Vec::new(),
+        histogram: None,
+    })
+}
+
+fn bpm_histogram(
+    essentia: &Essentia,
+    beat_intervals: &[f32],
+) -> Result<Option<BpmHistogram>, AlgorithmError> {
+    if beat_intervals.is_empty() {
+        return Ok(None);
+    }
+
+    let output = essentia
+        .try_create::<BpmHistogramDescriptors>()?
+        .configure()?
+        .compute(beat_intervals)?
+        .into_owned();
+
+    Ok(Some(BpmHistogram {
+        histogram: output.histogram,
+        first_peak: HistogramPeak {
+            bpm: output.first_peak_bpm,
+            weight: output.first_peak_weight,
+            spread: output.first_peak_spread,
+        },
+        second_peak: HistogramPeak {
+            bpm: output.second_peak_bpm,
+            weight: output.second_peak_weight,
+            spread: output.second_peak_spread,
+        },
+    }))
+}
+
+fn intervals(beats: &[f32]) -> Vec<f32> {
+    beats.windows(2).map(|pair| pair[1] - pair[0]).collect()
+}
+
+/// Converts the median beat interval to a tempo, which is robust against
+/// the occasional missed or doubled beat.
+fn bpm_from_intervals(beat_intervals: &[f32]) -> f32 {
+    if beat_intervals.is_empty() {
+        return 0.0;
+    }
+
+    let mut sorted = beat_intervals.to_vec();
+    sorted.sort_by(f32::total_cmp);
+
+    let middle = sorted.len() / 2;
+    let median = if sorted.len() % 2 == 0 {
+        (sorted[middle - 1] + sorted[middle]) / 2.0
+    } else {
+        sorted[middle]
+    };
+
+    60.0 / median
+}
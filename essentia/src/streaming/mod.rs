@@ -0,0 +1,18 @@
+pub use essentia_core::streaming::{
+    ConnectionError, Network, NetworkError, Node, Sink, Source, VectorOutput,
+};
+
+use crate::{AlgorithmError, Essentia};
+
+pub trait CreateStreamingAlgorithm<'a>: Sized {
+    fn try_create(essentia: &'a Essentia) -> Result<Self, AlgorithmError>;
+
+    fn create(essentia: &'a Essentia) -> Self {
+        match Self::try_create(essentia) {
+            Ok(algorithm) => algorithm,
+            Err(error) => panic!("Failed to create streaming algorithm: {}", error),
+        }
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/streaming/mod.rs"));
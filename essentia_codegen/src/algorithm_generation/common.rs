@@ -60,3 +60,37 @@ pub fn data_type_enum_to_data_type_marker(data_type: &DataType) -> TokenStream {
         DataType::Pool => quote! { crate::data_type::Pool},
     }
 }
+
+pub fn data_type_enum_to_owned_type(data_type: &DataType) -> TokenStream {
+    match data_type {
+        DataType::Bool => quote! { bool },
+        DataType::Float => quote! { f32 },
+        DataType::String => quote! { String },
+        DataType::Int => quote! { i32 },
+        DataType::UnsignedInt => quote! { u32 },
+        DataType::Long => quote! { i64 },
+        DataType::StereoSample => quote! { crate::data::StereoSample },
+        DataType::Complex => quote! { num::Complex<f32> },
+        DataType::TensorFloat => quote! { ndarray::Array4<f32> },
+        DataType::VectorFloat => quote! { Vec<f32> },
+        DataType::VectorString => quote! { Vec<String> },
+        DataType::VectorBool => quote! { Vec<bool> },
+        DataType::VectorInt => quote! { Vec<i32> },
+        DataType::VectorStereoSample => quote! { Vec<crate::data::StereoSample> },
+        DataType::VectorComplex => quote! { Vec<num::Complex<f32>> },
+        DataType::VectorVectorFloat => quote! { Vec<Vec<f32>> },
+        DataType::VectorVectorString => quote! { Vec<Vec<String>> },
+        DataType::VectorVectorStereoSample => quote! { Vec<Vec<crate::data::StereoSample>> },
+        DataType::VectorVectorComplex => quote! { Vec<Vec<num::Complex<f32>>> },
+        DataType::VectorMatrixFloat => quote! { Vec<ndarray::Array2<f32>> },
+        DataType::MapVectorFloat => quote! { std::collections::HashMap<String, Vec<f32>> },
+        DataType::MapVectorString => quote! { std::collections::HashMap<String, Vec<String>> },
+        DataType::MapVectorInt => quote! { std::collections::HashMap<String, Vec<i32>> },
+        DataType::MapVectorComplex => {
+            quote! { std::collections::HashMap<String, Vec<num::Complex<f32>>> }
+        }
+        DataType::MapFloat => quote! { std::collections::HashMap<String, f32> },
+        DataType::MatrixFloat => quote! { ndarray::Array2<f32> },
+        DataType::Pool => quote! { crate::Pool },
+    }
+}
@@ -8,13 +8,14 @@ use syn::parse_quote;
 
 use crate::algorithm_generation::{
     common::string_to_doc_comment, compute_function::generate_compute_function,
-    output_functions::generate_output_functions, parameter_functions::generate_parameter_functions,
+    output_functions::{generate_output_functions, generate_owned_output},
+    parameter_functions::generate_parameter_functions,
 };
 
-mod common;
+pub(crate) mod common;
 mod compute_function;
 mod output_functions;
-mod parameter_functions;
+pub(crate) mod parameter_functions;
 
 pub struct GeneratedAlgorithm {
     pub algorithm_module_name: String,
@@ -30,15 +31,23 @@ pub fn generate_algorithm_module(introspection: &Introspection) -> syn::File {
         &introspection.name().trim().to_case(Case::Pascal)
     );
 
+    let algorithm_output_struct_name = format_ident!(
+        "{}Output",
+        &introspection.name().trim().to_case(Case::Pascal)
+    );
+
     let algorithm_name = introspection.name();
     let description = string_to_doc_comment(introspection.description());
     let parameter_result = generate_parameter_functions(introspection);
     let compute_function =
         generate_compute_function(algorithm_result_struct_name.clone(), introspection);
     let output_functions = generate_output_functions(introspection);
+    let owned_output = generate_owned_output(algorithm_output_struct_name, introspection);
 
     let constraint_code = &parameter_result.constraint_code;
     let parameter_functions = &parameter_result.functions;
+    let owned_output_struct = &owned_output.struct_code;
+    let into_owned_function = &owned_output.into_owned_function;
 
     parse_quote! {
         #constraint_code
@@ -67,19 +76,30 @@ pub fn generate_algorithm_module(introspection: &Introspection) -> syn::File {
         }
 
         impl <'a> #algorithm_struct_name<'a, crate::Configured> {
+            /// Get the parameters the algorithm is configured with
+            ///
+            /// Contains the explicitly set parameters merged with the defaults of the algorithm.
+            pub fn parameters(&self) -> Result<std::collections::HashMap<String, crate::Value>, crate::algorithm::ParameterError> {
+                self.algorithm.parameters()
+            }
+
+            /// Return the algorithm to the initialized state
+            ///
+            /// Keeps the current configuration, so only the parameters that change need to be set again.
+            pub fn reconfigure(self) -> Result<#algorithm_struct_name<'a, crate::Initialized>, crate::algorithm::ParameterError> {
+                Ok(#algorithm_struct_name {
+                    algorithm: self.algorithm.reconfigure()?,
+                })
+            }
+
             #compute_function
         }
 
         impl<'a> crate::algorithm::CreateAlgorithm<'a> for #algorithm_struct_name<'a, crate::Initialized> {
-            fn create(essentia: &'a crate::Essentia) -> Self {
-                let algorithm = match essentia.inner.create_algorithm(#algorithm_name) {
-                    Ok(algorithm) => algorithm,
-                    Err(essentia_core::CreateAlgorithmError::AlgorithmNotFound { name }) => {
-                        panic!("Algorithm '{}' not found in Essentia", name);
-                    }
-                };
-
-                Self { algorithm }
+            fn try_create(essentia: &'a crate::Essentia) -> Result<Self, crate::AlgorithmError> {
+                let algorithm = essentia.inner.create_algorithm(#algorithm_name)?;
+
+                Ok(Self { algorithm })
             }
         }
 
@@ -90,13 +110,18 @@ pub fn generate_algorithm_module(introspection: &Introspection) -> syn::File {
 
         impl <'algorithm, 'result> #algorithm_result_struct_name<'algorithm, 'result> {
             #(#output_functions)*
+
+            #into_owned_function
         }
+
+        #owned_output_struct
     }
 }
 
 pub fn generate_algorithm_module_file(
     introspection: &Introspection,
     out_dir: &Path,
+    generate_module: fn(&Introspection) -> syn::File,
 ) -> std::io::Result<GeneratedAlgorithm> {
     let algorithm_module_name = introspection.name().trim().to_case(Case::Snake);
     let category_module_name = Regex::new(r"\W+")
@@ -111,7 +136,7 @@ pub fn generate_algorithm_module_file(
 
     std::fs::create_dir_all(&category_module_directory_path)?;
 
-    let syntax_tree = generate_algorithm_module(introspection);
+    let syntax_tree = generate_module(introspection);
     let formatted = prettyplease::unparse(&syntax_tree);
     std::fs::write(&algorithm_module_file_path, formatted)?;
 
@@ -2,9 +2,11 @@ use convert_case::{Case, Casing};
 use essentia_core::Introspection;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
+use syn::Ident;
 
 use crate::algorithm_generation::common::{
-    data_type_enum_to_data_type_marker, sanitize_identifier_string, string_to_doc_comment,
+    data_type_enum_to_data_type_marker, data_type_enum_to_owned_type, sanitize_identifier_string,
+    string_to_doc_comment,
 };
 
 pub fn generate_output_functions(introspection: &Introspection) -> Vec<TokenStream> {
@@ -41,3 +43,58 @@ pub fn generate_output_functions(introspection: &Introspection) -> Vec<TokenStre
         })
         .collect()
 }
+
+pub struct OwnedOutputResult {
+    pub struct_code: TokenStream,
+    pub into_owned_function: TokenStream,
+}
+
+pub fn generate_owned_output(
+    algorithm_output_struct_name: Ident,
+    introspection: &Introspection,
+) -> OwnedOutputResult {
+    let mut outputs: Vec<_> = introspection.outputs().collect();
+    outputs.sort_by(|a, b| a.name().cmp(b.name()));
+
+    let mut fields = Vec::new();
+    let mut field_initializers = Vec::new();
+
+    for output in &outputs {
+        let field_name = format_ident!(
+            "{}",
+            &sanitize_identifier_string(&output.name().to_case(Case::Snake))
+        );
+        let owned_type = data_type_enum_to_owned_type(&output.input_output_type());
+        let doc_comment = string_to_doc_comment(output.description());
+
+        fields.push(quote! {
+            #doc_comment
+            pub #field_name: #owned_type
+        });
+        field_initializers.push(quote! {
+            #field_name: crate::data::GetFromDataContainer::get(&self.#field_name())
+        });
+    }
+
+    let struct_code = quote! {
+        /// Owned copy of all outputs of a computation, detached from the algorithm.
+        #[derive(Debug, Clone)]
+        pub struct #algorithm_output_struct_name {
+            #(#fields,)*
+        }
+    };
+
+    let into_owned_function = quote! {
+        /// Copy all outputs of the computation into an owned struct.
+        pub fn into_owned(self) -> #algorithm_output_struct_name {
+            #algorithm_output_struct_name {
+                #(#field_initializers,)*
+            }
+        }
+    };
+
+    OwnedOutputResult {
+        struct_code,
+        into_owned_function,
+    }
+}
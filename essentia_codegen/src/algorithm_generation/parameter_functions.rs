@@ -15,6 +15,16 @@ fn generate_parameter_function_docs(parameter: &ParameterInfo) -> TokenStream {
     string_to_doc_comment(&doc)
 }
 
+fn generate_try_parameter_function_docs(parameter: &ParameterInfo) -> TokenStream {
+    let name = parameter.name();
+    let doc = format!(
+        "Sets the `{}` parameter, returning an error instead of panicking if it is rejected.",
+        name
+    );
+
+    string_to_doc_comment(&doc)
+}
+
 pub struct ConstraintInfo {
     trait_ident: syn::Ident,
     constraint_code: TokenStream,
@@ -88,8 +98,10 @@ pub fn generate_parameter_functions(algorithm_introspection: &Introspection) ->
         .map(|parameter| {
             let parameter_name = parameter.name();
             let function_name = format_ident!("{}", sanitize_identifier_string(&parameter_name.to_case(Case::Snake)));
+            let try_function_name = format_ident!("try_{}", parameter_name.to_case(Case::Snake));
             let data_type_variant = data_type_enum_to_data_type_marker(&parameter.parameter_type());
             let doc_comment = generate_parameter_function_docs(parameter);
+            let try_doc_comment = generate_try_parameter_function_docs(parameter);
             
             let type_constraint = match generate_constraint(algorithm_name, parameter) {
                 Some(constraint_info) => {
@@ -103,21 +115,24 @@ pub fn generate_parameter_functions(algorithm_introspection: &Introspection) ->
             };
 
             quote! {
+                #try_doc_comment
+                pub fn #try_function_name<T>(mut self, value: T) -> Result<Self, crate::AlgorithmError>
+                where
+                    T: #type_constraint
+                {
+                    self.algorithm.set_parameter(#parameter_name, value)?;
+                    Ok(self)
+                }
+
                 #doc_comment
-                pub fn #function_name<T>(mut self, value: T) -> Self 
-                where 
-                    T: #type_constraint 
+                pub fn #function_name<T>(self, value: T) -> Self
+                where
+                    T: #type_constraint
                 {
-                    match self.algorithm.set_parameter(#parameter_name, value) {
-                        Ok(_) => {},
-                        Err(essentia_core::algorithm::ParameterError::ParameterNotFound { parameter }) => {
-                            panic!("Parameter '{}' not found after validation", parameter);
-                        }
-                        Err(essentia_core::algorithm::ParameterError::TypeMismatch { parameter, expected, actual }) => {
-                            panic!("Type mismatch for parameter '{}': expected {:?}, found {:?}", parameter, expected, actual);
-                        }
+                    match self.#try_function_name(value) {
+                        Ok(algorithm) => algorithm,
+                        Err(error) => panic!("Failed to set parameter '{}': {}", #parameter_name, error),
                     }
-                    self
                 }
             }
         })
@@ -0,0 +1,34 @@
+//! Captures the introspection of the linked Essentia into a JSON snapshot,
+//! which the `essentia` crate can generate its bindings from.
+//!
+//! Usage: `cargo run -p essentia-codegen --bin essentia-snapshot -- [OUTPUT]`
+
+use std::path::PathBuf;
+
+use essentia_codegen::IntrospectionSnapshot;
+use essentia_core::essentia::Essentia;
+
+fn main() -> std::io::Result<()> {
+    let output = std::env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("essentia/introspection.json"));
+
+    let essentia = Essentia::new();
+    let snapshot = IntrospectionSnapshot::capture(&essentia);
+
+    for algorithm_name in &snapshot.skipped_streaming_algorithms {
+        eprintln!("Skipping streaming algorithm '{}'", algorithm_name);
+    }
+
+    snapshot.save(&output)?;
+
+    println!(
+        "Wrote {} algorithms and {} streaming algorithms to {}",
+        snapshot.algorithms.len(),
+        snapshot.streaming_algorithms.len(),
+        output.display()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+/// Optional dependencies of Essentia that can be toggled through cargo features.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Feature {
+    Tensorflow,
+    Ffmpeg,
+    Chromaprint,
+    Taglib,
+    Samplerate,
+    Yaml,
+}
+
+impl Feature {
+    fn enabled(self) -> bool {
+        match self {
+            Feature::Tensorflow => cfg!(feature = "tensorflow"),
+            Feature::Ffmpeg => cfg!(feature = "ffmpeg"),
+            Feature::Chromaprint => cfg!(feature = "chromaprint"),
+            Feature::Taglib => cfg!(feature = "taglib"),
+            Feature::Samplerate => cfg!(feature = "samplerate"),
+            Feature::Yaml => cfg!(feature = "yaml"),
+        }
+    }
+}
+
+/// Mirrors the algorithm exclusions in Essentia's own build configuration
+/// when one of its optional dependencies is missing.
+fn required_features(algorithm_name: &str) -> &'static [Feature] {
+    use Feature::*;
+
+    match algorithm_name {
+        "AudioLoader" | "AudioWriter" | "MonoWriter" => &[Ffmpeg],
+        "MonoLoader" | "EasyLoader" | "EqloudLoader" => &[Ffmpeg, Samplerate],
+        "MusicExtractor" | "FreesoundExtractor" => &[Ffmpeg, Samplerate, Taglib, Yaml],
+        "Resample" => &[Samplerate],
+        "MetadataReader" => &[Taglib],
+        "Chromaprinter" => &[Chromaprint],
+        "YamlInput" | "YamlOutput" => &[Yaml],
+        "PitchCREPE" | "TempoCNN" => &[Tensorflow],
+        name if name.starts_with("Tensorflow") => &[Tensorflow],
+        _ => &[],
+    }
+}
+
+pub(crate) fn is_algorithm_enabled(algorithm_name: &str) -> bool {
+    required_features(algorithm_name)
+        .iter()
+        .all(|feature| feature.enabled())
+}
@@ -1,13 +1,24 @@
 mod algorithm_generation;
+mod features;
 mod module_generation;
+mod snapshot;
+mod streaming_generation;
 
-use algorithm_generation::{GeneratedAlgorithm, generate_algorithm_module_file};
+use algorithm_generation::{
+    GeneratedAlgorithm, generate_algorithm_module, generate_algorithm_module_file,
+};
+#[cfg(feature = "introspect")]
 use essentia_core::essentia::Essentia;
 use std::collections::HashMap;
 use std::path::Path;
 
 use crate::module_generation::category_module::generate_category_module_file;
 use crate::module_generation::main_module::generate_main_module_file;
+use crate::streaming_generation::generate_streaming_algorithm_module;
+
+pub use snapshot::{
+    AlgorithmSnapshot, InputOutputSnapshot, IntrospectionSnapshot, ParameterSnapshot,
+};
 
 fn generate_module_files(
     out_dir: &Path,
@@ -35,20 +46,63 @@ fn generate_module_files(
     Ok(())
 }
 
+/// Generates the algorithm bindings by introspecting the linked Essentia.
+#[cfg(feature = "introspect")]
 pub fn generate_code(out_dir: &Path) -> std::io::Result<()> {
     let essentia = Essentia::new();
+    let snapshot = IntrospectionSnapshot::capture(&essentia);
+
+    for algorithm_name in &snapshot.skipped_streaming_algorithms {
+        println!(
+            "cargo:warning=Skipping streaming algorithm '{}'",
+            algorithm_name
+        );
+    }
+
+    generate_code_from_snapshot(out_dir, &snapshot)
+}
+
+/// Generates the algorithm bindings from a previously captured snapshot,
+/// without running Essentia.
+pub fn generate_code_from_snapshot(
+    out_dir: &Path,
+    snapshot: &IntrospectionSnapshot,
+) -> std::io::Result<()> {
+    let algorithms_directory = out_dir.join("algorithms");
+    std::fs::create_dir_all(&algorithms_directory)?;
+
+    let results: Vec<GeneratedAlgorithm> = snapshot
+        .introspections()?
+        .iter()
+        .filter(|introspection| features::is_algorithm_enabled(introspection.name()))
+        .map(|introspection| {
+            generate_algorithm_module_file(
+                introspection,
+                &algorithms_directory,
+                generate_algorithm_module,
+            )
+        })
+        .collect::<std::io::Result<_>>()?;
+
+    generate_module_files(&algorithms_directory, &results)?;
 
-    let results: Vec<GeneratedAlgorithm> = essentia
-        .available_algorithms()
-        .map(|algorithm_name| {
-            let algorithm = essentia.create_algorithm(algorithm_name).unwrap();
-            let introspection = algorithm.introspection();
+    let streaming_directory = out_dir.join("streaming");
+    std::fs::create_dir_all(&streaming_directory)?;
 
-            generate_algorithm_module_file(introspection, out_dir)
+    let streaming_results: Vec<GeneratedAlgorithm> = snapshot
+        .streaming_introspections()?
+        .iter()
+        .filter(|introspection| features::is_algorithm_enabled(introspection.name()))
+        .map(|introspection| {
+            generate_algorithm_module_file(
+                introspection,
+                &streaming_directory,
+                generate_streaming_algorithm_module,
+            )
         })
         .collect::<std::io::Result<_>>()?;
 
-    generate_module_files(out_dir, &results)?;
+    generate_module_files(&streaming_directory, &streaming_results)?;
 
     Ok(())
 }
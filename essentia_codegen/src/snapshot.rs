@@ -0,0 +1,236 @@
+use std::{io, path::Path};
+
+#[cfg(feature = "introspect")]
+use essentia_core::essentia::Essentia;
+use essentia_core::{
+    DataType, Introspection,
+    algorithm::{Constraint, InputOutputInfo, ParameterInfo},
+};
+use serde::{Deserialize, Serialize};
+
+/// Serialized introspection of every algorithm, so that code can be generated
+/// without a working Essentia on the build host.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IntrospectionSnapshot {
+    pub algorithms: Vec<AlgorithmSnapshot>,
+    pub streaming_algorithms: Vec<AlgorithmSnapshot>,
+    /// Streaming algorithms that Essentia failed to instantiate.
+    #[serde(default)]
+    pub skipped_streaming_algorithms: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlgorithmSnapshot {
+    pub name: String,
+    pub category: String,
+    pub description: String,
+    pub inputs: Vec<InputOutputSnapshot>,
+    pub outputs: Vec<InputOutputSnapshot>,
+    pub parameters: Vec<ParameterSnapshot>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InputOutputSnapshot {
+    pub name: String,
+    pub data_type: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParameterSnapshot {
+    pub name: String,
+    pub data_type: String,
+    pub description: String,
+    pub constraint: String,
+    pub default_value: String,
+}
+
+impl IntrospectionSnapshot {
+    /// Introspects all algorithms of a running Essentia.
+    #[cfg(feature = "introspect")]
+    pub fn capture(essentia: &Essentia) -> Self {
+        let mut algorithm_names: Vec<&str> = essentia.available_algorithms().collect();
+        algorithm_names.sort();
+
+        let algorithms = algorithm_names
+            .into_iter()
+            .map(|algorithm_name| {
+                let algorithm = essentia.create_algorithm(algorithm_name).unwrap();
+                AlgorithmSnapshot::from(algorithm.introspection())
+            })
+            .collect();
+
+        let mut streaming_algorithm_names: Vec<&str> =
+            essentia.available_streaming_algorithms().collect();
+        streaming_algorithm_names.sort();
+
+        let mut streaming_algorithms = Vec::new();
+        let mut skipped_streaming_algorithms = Vec::new();
+
+        for algorithm_name in streaming_algorithm_names {
+            match essentia.create_streaming_algorithm(algorithm_name) {
+                Ok(algorithm) => {
+                    streaming_algorithms.push(AlgorithmSnapshot::from(algorithm.introspection()))
+                }
+                Err(_) => skipped_streaming_algorithms.push(algorithm_name.to_string()),
+            }
+        }
+
+        Self {
+            algorithms,
+            streaming_algorithms,
+            skipped_streaming_algorithms,
+        }
+    }
+
+    pub fn from_json(json: &str) -> io::Result<Self> {
+        serde_json::from_str(json)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    pub fn to_json(&self) -> io::Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Self::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut json = self.to_json()?;
+        json.push('\n');
+        std::fs::write(path, json)
+    }
+
+    pub fn introspections(&self) -> io::Result<Vec<Introspection>> {
+        self.algorithms
+            .iter()
+            .map(AlgorithmSnapshot::to_introspection)
+            .collect()
+    }
+
+    pub fn streaming_introspections(&self) -> io::Result<Vec<Introspection>> {
+        self.streaming_algorithms
+            .iter()
+            .map(AlgorithmSnapshot::to_introspection)
+            .collect()
+    }
+}
+
+impl AlgorithmSnapshot {
+    pub fn to_introspection(&self) -> io::Result<Introspection> {
+        let inputs = self
+            .inputs
+            .iter()
+            .map(InputOutputSnapshot::to_info)
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let outputs = self
+            .outputs
+            .iter()
+            .map(InputOutputSnapshot::to_info)
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let parameters = self
+            .parameters
+            .iter()
+            .map(ParameterSnapshot::to_info)
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(Introspection::new(
+            &self.name,
+            &self.category,
+            &self.description,
+            inputs,
+            outputs,
+            parameters,
+        ))
+    }
+}
+
+impl From<&Introspection> for AlgorithmSnapshot {
+    fn from(introspection: &Introspection) -> Self {
+        let mut inputs: Vec<InputOutputSnapshot> = introspection
+            .inputs()
+            .map(InputOutputSnapshot::from)
+            .collect();
+        inputs.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut outputs: Vec<InputOutputSnapshot> = introspection
+            .outputs()
+            .map(InputOutputSnapshot::from)
+            .collect();
+        outputs.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut parameters: Vec<ParameterSnapshot> = introspection
+            .parameters()
+            .map(ParameterSnapshot::from)
+            .collect();
+        parameters.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            name: introspection.name().to_string(),
+            category: introspection.category().to_string(),
+            description: introspection.description().to_string(),
+            inputs,
+            outputs,
+            parameters,
+        }
+    }
+}
+
+impl InputOutputSnapshot {
+    fn to_info(&self) -> io::Result<InputOutputInfo> {
+        Ok(InputOutputInfo::new(
+            &self.name,
+            parse_data_type(&self.data_type)?,
+            &self.description,
+        ))
+    }
+}
+
+impl From<&InputOutputInfo> for InputOutputSnapshot {
+    fn from(info: &InputOutputInfo) -> Self {
+        Self {
+            name: info.name().to_string(),
+            data_type: info.input_output_type().as_str().to_string(),
+            description: info.description().to_string(),
+        }
+    }
+}
+
+impl ParameterSnapshot {
+    fn to_info(&self) -> io::Result<ParameterInfo> {
+        let data_type = parse_data_type(&self.data_type)?;
+
+        Ok(ParameterInfo::new(
+            &self.name,
+            data_type,
+            &self.description,
+            Constraint::for_parameter(&self.constraint, data_type),
+            &self.default_value,
+        ))
+    }
+}
+
+impl From<&ParameterInfo> for ParameterSnapshot {
+    fn from(info: &ParameterInfo) -> Self {
+        Self {
+            name: info.name().to_string(),
+            data_type: info.parameter_type().as_str().to_string(),
+            description: info.description().to_string(),
+            // Essentia reports unconstrained parameters with an empty string
+            constraint: match info.constraint() {
+                Constraint::Any => String::new(),
+                constraint => constraint.to_string(),
+            },
+            default_value: info.default_value().to_string(),
+        }
+    }
+}
+
+fn parse_data_type(name: &str) -> io::Result<DataType> {
+    name.parse()
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
@@ -0,0 +1,84 @@
+use convert_case::{Case, Casing};
+use essentia_core::Introspection;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::algorithm_generation::common::{
+    data_type_enum_to_data_type_marker, sanitize_identifier_string, string_to_doc_comment,
+};
+
+pub fn generate_source_functions(introspection: &Introspection) -> Vec<TokenStream> {
+    introspection
+        .outputs()
+        .map(|source| {
+            let method_name = format_ident!(
+                "source_{}",
+                &sanitize_identifier_string(&source.name().to_case(Case::Snake))
+            );
+            let source_name = source.name();
+            let variant = data_type_enum_to_data_type_marker(&source.input_output_type());
+
+            let doc_comment = string_to_doc_comment(&format!(
+                "Get the `{}` source of this node.\n\n# Description\n\n{}",
+                source_name,
+                source.description()
+            ));
+
+            quote! {
+                #doc_comment
+                pub fn #method_name(&self) -> crate::streaming::Source<#variant> {
+                    match self.node.source(#source_name) {
+                        Ok(source) => source,
+                        Err(essentia_core::streaming::ConnectionError::SourceNotFound { source_name, .. }) => {
+                            panic!("Source '{}' not found after validation", source_name);
+                        }
+                        Err(essentia_core::streaming::ConnectionError::TypeMismatch { connector, expected, actual }) => {
+                            panic!("Type mismatch for source '{}': expected {:?}, found {:?}", connector, expected, actual);
+                        }
+                        Err(error) => {
+                            panic!("Failed to resolve source '{}': {}", #source_name, error);
+                        }
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+pub fn generate_sink_functions(introspection: &Introspection) -> Vec<TokenStream> {
+    introspection
+        .inputs()
+        .map(|sink| {
+            let method_name = format_ident!(
+                "sink_{}",
+                &sanitize_identifier_string(&sink.name().to_case(Case::Snake))
+            );
+            let sink_name = sink.name();
+            let variant = data_type_enum_to_data_type_marker(&sink.input_output_type());
+
+            let doc_comment = string_to_doc_comment(&format!(
+                "Get the `{}` sink of this node.\n\n# Description\n\n{}",
+                sink_name,
+                sink.description()
+            ));
+
+            quote! {
+                #doc_comment
+                pub fn #method_name(&self) -> crate::streaming::Sink<#variant> {
+                    match self.node.sink(#sink_name) {
+                        Ok(sink) => sink,
+                        Err(essentia_core::streaming::ConnectionError::SinkNotFound { sink_name, .. }) => {
+                            panic!("Sink '{}' not found after validation", sink_name);
+                        }
+                        Err(essentia_core::streaming::ConnectionError::TypeMismatch { connector, expected, actual }) => {
+                            panic!("Type mismatch for sink '{}': expected {:?}, found {:?}", connector, expected, actual);
+                        }
+                        Err(error) => {
+                            panic!("Failed to resolve sink '{}': {}", #sink_name, error);
+                        }
+                    }
+                }
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,92 @@
+use convert_case::{Case, Casing};
+use essentia_core::Introspection;
+use quote::format_ident;
+use syn::parse_quote;
+
+use crate::{
+    algorithm_generation::{
+        common::string_to_doc_comment, parameter_functions::generate_parameter_functions,
+    },
+    streaming_generation::connector_functions::{
+        generate_sink_functions, generate_source_functions,
+    },
+};
+
+mod connector_functions;
+
+pub fn generate_streaming_algorithm_module(introspection: &Introspection) -> syn::File {
+    let algorithm_struct_name =
+        format_ident!("{}", &introspection.name().trim().to_case(Case::Pascal));
+
+    let algorithm_node_struct_name =
+        format_ident!("{}Node", &introspection.name().trim().to_case(Case::Pascal));
+
+    let algorithm_name = introspection.name();
+    let description = string_to_doc_comment(introspection.description());
+    let parameter_result = generate_parameter_functions(introspection);
+    let source_functions = generate_source_functions(introspection);
+    let sink_functions = generate_sink_functions(introspection);
+
+    let constraint_code = &parameter_result.constraint_code;
+    let parameter_functions = &parameter_result.functions;
+
+    parse_quote! {
+        #constraint_code
+
+        #description
+        #[allow(dead_code)]
+        pub struct #algorithm_struct_name<'a, State = crate::Initialized> {
+            algorithm: essentia_core::streaming::StreamingAlgorithm<'a, State>
+        }
+
+        impl <'a> #algorithm_struct_name<'a, crate::Initialized> {
+            #(#parameter_functions)*
+
+            /// Configure the algorithm with the set parameters
+            ///
+            /// Returns a configured algorithm ready to be added to a network.
+            pub fn configure(self) -> Result<#algorithm_struct_name<'a, crate::Configured>, crate::algorithm::ConfigurationError> {
+                Ok(#algorithm_struct_name {
+                    algorithm: self.algorithm.configure().map_err(|e| match e {
+                        essentia_core::algorithm::ConfigurationError::Internal(exception) => {
+                            crate::algorithm::ConfigurationError::Internal(exception)
+                        }
+                    })?,
+                })
+            }
+        }
+
+        impl <'a> #algorithm_struct_name<'a, crate::Configured> {
+            /// Add the algorithm to a network
+            ///
+            /// Returns a node giving typed access to the sources and sinks of the algorithm.
+            pub fn add_to(self, network: &mut crate::streaming::Network<'a>) -> #algorithm_node_struct_name {
+                #algorithm_node_struct_name {
+                    node: network.add(self.algorithm),
+                }
+            }
+        }
+
+        impl<'a> crate::streaming::CreateStreamingAlgorithm<'a> for #algorithm_struct_name<'a, crate::Initialized> {
+            fn try_create(essentia: &'a crate::Essentia) -> Result<Self, crate::AlgorithmError> {
+                let algorithm = essentia.inner.create_streaming_algorithm(#algorithm_name)?;
+
+                Ok(Self { algorithm })
+            }
+        }
+
+        pub struct #algorithm_node_struct_name {
+            node: crate::streaming::Node
+        }
+
+        impl #algorithm_node_struct_name {
+            pub fn node(&self) -> &crate::streaming::Node {
+                &self.node
+            }
+
+            #(#source_functions)*
+
+            #(#sink_functions)*
+        }
+    }
+}
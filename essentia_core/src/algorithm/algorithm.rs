@@ -1,20 +1,43 @@
 use cxx::UniquePtr;
 use essentia_sys::ffi;
-use std::marker::PhantomData;
+use std::{collections::HashMap, marker::PhantomData};
 
 use crate::{
     IntoDataContainer,
     algorithm::{
         ComputeError, ConfigurationError, InputError, Introspection, OutputError, ParameterError,
-        ResetError,
+        ParameterInfo, ResetError,
+    },
+    data::{
+        DataContainer, DataType, InputOutputData, ParameterData, Value, data_type,
+        types::HasDataType,
     },
-    data::{DataContainer, InputOutputData, ParameterData, types::HasDataType},
     essentia::Essentia,
     parameter_map::ParameterMap,
 };
 
 pub struct Initialized {
-    parameter_map: ParameterMap,
+    pub(crate) parameters: HashMap<String, DataContainer<'static, data_type::Any>>,
+}
+
+impl Initialized {
+    pub(crate) fn new() -> Self {
+        Self {
+            parameters: HashMap::new(),
+        }
+    }
+
+    /// Builds the bridge map only when configuring, since Essentia's
+    /// `ParameterMap` does not overwrite a parameter that was already added.
+    pub(crate) fn into_parameter_map(self) -> ParameterMap {
+        let mut parameter_map = ParameterMap::new();
+
+        for (name, value) in self.parameters {
+            parameter_map.set_parameter(&name, value);
+        }
+
+        parameter_map
+    }
 }
 
 pub struct Configured;
@@ -38,9 +61,7 @@ impl<'a> Algorithm<'a, Initialized> {
 
         Self {
             algorithm_bridge,
-            state: Initialized {
-                parameter_map: ParameterMap::new(),
-            },
+            state: Initialized::new(),
             introspection,
             _marker: PhantomData,
         }
@@ -66,34 +87,23 @@ impl<'a> Algorithm<'a, Initialized> {
     where
         T: ParameterData + HasDataType,
     {
-        let param_info = self.introspection.get_parameter(key).ok_or_else(|| {
-            ParameterError::ParameterNotFound {
-                parameter: key.to_string(),
-            }
-        })?;
-
-        let expected_type = T::data_type();
-        let param_data_type = param_info.parameter_type();
-
-        if param_data_type != expected_type {
-            return Err(ParameterError::TypeMismatch {
-                parameter: key.to_string(),
-                expected: expected_type,
-                actual: param_data_type,
-            });
-        }
-
         let data_container = value.into_data_container();
 
-        self.state.parameter_map.set_parameter(key, data_container);
+        validate_parameter(&self.introspection, key, &data_container)?;
+
+        self.state
+            .parameters
+            .insert(key.to_string(), data_container.into_any());
 
         Ok(())
     }
 
     pub fn configure(mut self) -> Result<Algorithm<'a, Configured>, ConfigurationError> {
+        let parameter_map = self.state.into_parameter_map();
+
         self.algorithm_bridge
             .pin_mut()
-            .configure(self.state.parameter_map.parameter_map_bridge)?;
+            .configure(parameter_map.parameter_map_bridge)?;
 
         Ok(Algorithm {
             algorithm_bridge: self.algorithm_bridge,
@@ -104,7 +114,151 @@ impl<'a> Algorithm<'a, Initialized> {
     }
 }
 
+pub(crate) fn validate_parameter<T>(
+    introspection: &Introspection,
+    key: &str,
+    data_container: &DataContainer<'_, T>,
+) -> Result<(), ParameterError>
+where
+    T: ParameterData + HasDataType,
+{
+    validate_parameter_data(
+        introspection,
+        key,
+        T::data_type(),
+        data_container.inner.as_ref(),
+    )
+}
+
+/// Checks a parameter value whose type is only known at runtime.
+pub(crate) fn validate_parameter_data(
+    introspection: &Introspection,
+    key: &str,
+    expected_type: DataType,
+    data_container: &ffi::DataContainer,
+) -> Result<(), ParameterError> {
+    let param_info =
+        introspection
+            .get_parameter(key)
+            .ok_or_else(|| ParameterError::ParameterNotFound {
+                parameter: key.to_string(),
+            })?;
+
+    let param_data_type = param_info.parameter_type();
+
+    if param_data_type != expected_type {
+        return Err(ParameterError::TypeMismatch {
+            parameter: key.to_string(),
+            expected: expected_type,
+            actual: param_data_type,
+        });
+    }
+
+    validate_constraint(param_info, data_container)
+}
+
+fn validate_constraint(
+    param_info: &ParameterInfo,
+    data_container: &ffi::DataContainer,
+) -> Result<(), ParameterError> {
+    let constraint = param_info.constraint();
+
+    let violation = |value: String| ParameterError::ConstraintViolation {
+        parameter: param_info.name().to_string(),
+        constraint: constraint.clone(),
+        value,
+    };
+
+    let check_int = |value: i32| {
+        if constraint.allows_int(value as i64) {
+            Ok(())
+        } else {
+            Err(violation(value.to_string()))
+        }
+    };
+
+    let check_real = |value: f32| {
+        if constraint.allows_real(value) {
+            Ok(())
+        } else {
+            Err(violation(value.to_string()))
+        }
+    };
+
+    let check_str = |value: &str| {
+        if constraint.allows_str(value) {
+            Ok(())
+        } else {
+            Err(violation(value.to_string()))
+        }
+    };
+
+    match data_container.get_data_type() {
+        ffi::DataType::Float => data_container.get_float().map_or(Ok(()), check_real),
+        ffi::DataType::Int => data_container.get_int().map_or(Ok(()), check_int),
+        ffi::DataType::Bool => data_container.get_bool().map_or(Ok(()), |value| {
+            check_str(if value { "true" } else { "false" })
+        }),
+        ffi::DataType::String => data_container
+            .get_string()
+            .map_or(Ok(()), |value| check_str(&value)),
+        ffi::DataType::VectorFloat => data_container.get_vector_float().map_or(Ok(()), |values| {
+            values.iter().try_for_each(|value| check_real(*value))
+        }),
+        ffi::DataType::VectorInt => data_container.get_vector_int().map_or(Ok(()), |values| {
+            values.iter().try_for_each(|value| check_int(*value))
+        }),
+        ffi::DataType::VectorString => {
+            data_container.get_vector_string().map_or(Ok(()), |values| {
+                values.iter().try_for_each(|value| check_str(value))
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
 impl<'a> Algorithm<'a, Configured> {
+    /// Returns the parameters the algorithm is configured with, i.e. the
+    /// explicitly set parameters merged with the defaults of the algorithm.
+    pub fn parameters(&self) -> Result<HashMap<String, Value>, ParameterError> {
+        let mut parameters = HashMap::new();
+
+        for name in self.algorithm_bridge.get_parameter_names() {
+            let parameter = self
+                .algorithm_bridge
+                .get_parameter(&name)
+                .map_err(|exception| ParameterError::Read {
+                    parameter: name.clone(),
+                    source: exception,
+                })?;
+
+            if !parameter.is_null() {
+                let value = DataContainer::<data_type::Any>::new_borrowed(&parameter).to_value();
+                parameters.insert(name, value);
+            }
+        }
+
+        Ok(parameters)
+    }
+
+    /// Returns the algorithm to the `Initialized` state while keeping its
+    /// current configuration, so that only the parameters that change need to
+    /// be set before configuring it again.
+    pub fn reconfigure(self) -> Result<Algorithm<'a, Initialized>, ParameterError> {
+        let parameters = self
+            .parameters()?
+            .into_iter()
+            .map(|(name, value)| (name, value.into_data_container()))
+            .collect();
+
+        Ok(Algorithm {
+            algorithm_bridge: self.algorithm_bridge,
+            state: Initialized { parameters },
+            introspection: self.introspection,
+            _marker: PhantomData,
+        })
+    }
+
     pub fn input<T>(
         mut self,
         key: &str,
@@ -189,6 +343,28 @@ pub struct ComputeResult<'algorithm, 'result> {
 }
 
 impl<'algorithm, 'result> ComputeResult<'algorithm, 'result> {
+    /// Copies every output of the computation into a [`Value`], so the
+    /// results stay available after the algorithm computes again and can be
+    /// sent to other threads.
+    pub fn into_owned(self) -> HashMap<String, Value> {
+        self.algorithm
+            .introspection
+            .outputs()
+            .map(|output_info| {
+                let value = self
+                    .algorithm
+                    .algorithm_bridge
+                    .get_output(output_info.name())
+                    .map(|data_container| {
+                        DataContainer::<data_type::Any>::new_borrowed(data_container).to_value()
+                    })
+                    .expect("failed to get output after validation");
+
+                (output_info.name().to_string(), value)
+            })
+            .collect()
+    }
+
     pub fn output<T>(&self, key: &str) -> Result<DataContainer<'result, T>, OutputError>
     where
         T: InputOutputData + HasDataType,
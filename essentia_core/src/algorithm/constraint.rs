@@ -0,0 +1,465 @@
+use std::{fmt, ops::Bound};
+
+use crate::data::DataType;
+
+/// Whether an [`Interval`] bounds integer or real values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberKind {
+    Int,
+    Real,
+}
+
+impl NumberKind {
+    /// Returns the kind of number a parameter of the given type holds.
+    pub fn of(data_type: DataType) -> Self {
+        match data_type {
+            DataType::Int | DataType::UnsignedInt | DataType::Long | DataType::VectorInt => {
+                NumberKind::Int
+            }
+            _ => NumberKind::Real,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    kind: NumberKind,
+    lower: Bound<f64>,
+    upper: Bound<f64>,
+}
+
+impl Interval {
+    pub fn new(kind: NumberKind, lower: Bound<f64>, upper: Bound<f64>) -> Self {
+        Self { kind, lower, upper }
+    }
+
+    /// The integers in `[min,max]`.
+    pub fn int_range(min: i32, max: i32) -> Self {
+        Self::new(
+            NumberKind::Int,
+            Bound::Included(min as f64),
+            Bound::Included(max as f64),
+        )
+    }
+
+    /// The integers in `[0,inf)`.
+    pub fn non_negative_int() -> Self {
+        Self::new(NumberKind::Int, Bound::Included(0.0), Bound::Unbounded)
+    }
+
+    /// The integers in `[1,inf)`.
+    pub fn positive_int() -> Self {
+        Self::new(NumberKind::Int, Bound::Included(1.0), Bound::Unbounded)
+    }
+
+    /// The reals in `[0,inf)`.
+    pub fn non_negative_real() -> Self {
+        Self::new(NumberKind::Real, Bound::Included(0.0), Bound::Unbounded)
+    }
+
+    /// The reals in `(0,inf)`.
+    pub fn positive_real() -> Self {
+        Self::new(NumberKind::Real, Bound::Excluded(0.0), Bound::Unbounded)
+    }
+
+    pub fn kind(&self) -> NumberKind {
+        self.kind
+    }
+
+    pub fn lower(&self) -> Bound<f64> {
+        self.lower
+    }
+
+    pub fn upper(&self) -> Bound<f64> {
+        self.upper
+    }
+
+    /// Checks a value of either kind. Integer intervals only contain whole
+    /// numbers.
+    pub fn contains(&self, value: f64) -> bool {
+        match self.kind {
+            NumberKind::Int if value.fract() != 0.0 => false,
+            _ => Self::contains_in(value, self.lower, self.upper),
+        }
+    }
+
+    pub fn contains_int(&self, value: i64) -> bool {
+        Self::contains_in(value as f64, self.lower, self.upper)
+    }
+
+    /// Checks a `Real` value the way Essentia does, against the bounds
+    /// rounded to `f32`, so that e.g. `0.1` is inside `(0,0.1]`.
+    pub fn contains_real(&self, value: f32) -> bool {
+        let to_real = |bound: Bound<f64>| bound.map(|endpoint| endpoint as f32);
+
+        Self::contains_in(value, to_real(self.lower), to_real(self.upper))
+    }
+
+    fn contains_in<T: PartialOrd>(value: T, lower: Bound<T>, upper: Bound<T>) -> bool {
+        let above_lower = match lower {
+            Bound::Included(min) => value >= min,
+            Bound::Excluded(min) => value > min,
+            Bound::Unbounded => true,
+        };
+
+        let below_upper = match upper {
+            Bound::Included(max) => value <= max,
+            Bound::Excluded(max) => value < max,
+            Bound::Unbounded => true,
+        };
+
+        above_lower && below_upper
+    }
+
+    /// Parses Essentia's interval notation, e.g. `[0,inf)`, `(0,1]` or
+    /// `[-inf,inf)`. Infinite endpoints are unbounded regardless of the bracket.
+    /// Without a `kind`, the interval is an integer one when every finite
+    /// endpoint is written as an integer.
+    fn parse(s: &str, kind: Option<NumberKind>) -> Option<Self> {
+        let s = s.trim();
+        let inner = s.get(1..s.len().checked_sub(1)?)?;
+        let (lower_str, upper_str) = inner.split_once(',')?;
+
+        let lower = match (s.chars().next()?, Self::parse_endpoint(lower_str)?) {
+            (_, None) => Bound::Unbounded,
+            ('[', Some(value)) => Bound::Included(value),
+            ('(', Some(value)) => Bound::Excluded(value),
+            _ => return None,
+        };
+
+        let upper = match (s.chars().last()?, Self::parse_endpoint(upper_str)?) {
+            (_, None) => Bound::Unbounded,
+            (']', Some(value)) => Bound::Included(value),
+            (')', Some(value)) => Bound::Excluded(value),
+            _ => return None,
+        };
+
+        let kind = kind.unwrap_or_else(|| {
+            let is_int = |endpoint: &str| {
+                let endpoint = endpoint.trim();
+                endpoint.ends_with("inf") || endpoint.parse::<i64>().is_ok()
+            };
+
+            if is_int(lower_str) && is_int(upper_str) {
+                NumberKind::Int
+            } else {
+                NumberKind::Real
+            }
+        });
+
+        Some(Self { kind, lower, upper })
+    }
+
+    fn parse_endpoint(s: &str) -> Option<Option<f64>> {
+        match s.trim() {
+            "inf" | "+inf" | "-inf" => Some(None),
+            value => value.parse::<f64>().ok().map(Some),
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.lower {
+            Bound::Included(min) => write!(f, "[{}", min)?,
+            Bound::Excluded(min) => write!(f, "({}", min)?,
+            Bound::Unbounded => write!(f, "(-inf")?,
+        }
+
+        match self.upper {
+            Bound::Included(max) => write!(f, ",{}]", max),
+            Bound::Excluded(max) => write!(f, ",{})", max),
+            Bound::Unbounded => write!(f, ",inf)"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constraint {
+    Any,
+    Interval(Interval),
+    OneOf(Vec<String>),
+    Union(Vec<Constraint>),
+    Custom(String),
+}
+
+/// Parses a constraint on its own, inferring the kind of its intervals from
+/// how their endpoints are written. Use [`Constraint::for_parameter`] when the
+/// parameter type is known.
+impl From<&str> for Constraint {
+    fn from(s: &str) -> Self {
+        Self::parse(s, None)
+    }
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Constraint::Any => write!(f, "any"),
+            Constraint::Interval(interval) => write!(f, "{}", interval),
+            Constraint::OneOf(values) => write!(f, "{{{}}}", values.join(",")),
+            Constraint::Union(constraints) => {
+                let parts: Vec<String> = constraints.iter().map(ToString::to_string).collect();
+                write!(f, "{}", parts.join(" U "))
+            }
+            Constraint::Custom(constraint) => write!(f, "{}", constraint),
+        }
+    }
+}
+
+impl Constraint {
+    /// Parses the constraint of a parameter, whose intervals bound integers
+    /// or reals depending on the type of the parameter.
+    pub fn for_parameter(s: &str, data_type: DataType) -> Self {
+        Self::parse(s, Some(NumberKind::of(data_type)))
+    }
+
+    fn parse(s: &str, kind: Option<NumberKind>) -> Self {
+        let s = s.trim();
+
+        if s.is_empty() {
+            return Constraint::Any;
+        }
+
+        let parts = Self::split_union(s);
+
+        if parts.len() > 1 {
+            let constraints: Option<Vec<Constraint>> = parts
+                .into_iter()
+                .map(|part| Self::parse_single(part, kind))
+                .collect();
+
+            return constraints
+                .map(Constraint::Union)
+                .unwrap_or_else(|| Constraint::Custom(s.to_string()));
+        }
+
+        Self::parse_single(s, kind).unwrap_or_else(|| Constraint::Custom(s.to_string()))
+    }
+
+    pub fn allows_number(&self, value: f64) -> bool {
+        match self {
+            Constraint::Interval(interval) => interval.contains(value),
+            Constraint::OneOf(values) => values
+                .iter()
+                .any(|option| option.parse::<f64>().is_ok_and(|option| option == value)),
+            Constraint::Union(constraints) => constraints
+                .iter()
+                .any(|constraint| constraint.allows_number(value)),
+            Constraint::Any | Constraint::Custom(_) => true,
+        }
+    }
+
+    pub fn allows_real(&self, value: f32) -> bool {
+        match self {
+            Constraint::Interval(interval) => interval.contains_real(value),
+            Constraint::OneOf(values) => values
+                .iter()
+                .any(|option| option.parse::<f32>().is_ok_and(|option| option == value)),
+            Constraint::Union(constraints) => constraints
+                .iter()
+                .any(|constraint| constraint.allows_real(value)),
+            Constraint::Any | Constraint::Custom(_) => true,
+        }
+    }
+
+    pub fn allows_int(&self, value: i64) -> bool {
+        match self {
+            Constraint::Interval(interval) => interval.contains_int(value),
+            Constraint::OneOf(values) => values
+                .iter()
+                .any(|option| option.parse::<i64>().is_ok_and(|option| option == value)),
+            Constraint::Union(constraints) => constraints
+                .iter()
+                .any(|constraint| constraint.allows_int(value)),
+            Constraint::Any | Constraint::Custom(_) => true,
+        }
+    }
+
+    pub fn allows_str(&self, value: &str) -> bool {
+        match self {
+            Constraint::OneOf(values) => values.iter().any(|option| option == value),
+            Constraint::Union(constraints) => constraints
+                .iter()
+                .any(|constraint| constraint.allows_str(value)),
+            _ => true,
+        }
+    }
+
+    /// Splits a constraint on the `U` union operator, ignoring any `U` that
+    /// appears inside a set or an interval.
+    fn split_union(s: &str) -> Vec<&str> {
+        let mut parts = Vec::new();
+        let mut depth = 0usize;
+        let mut start = 0;
+
+        for (index, character) in s.char_indices() {
+            match character {
+                '[' | '(' | '{' => depth += 1,
+                ']' | ')' | '}' => depth = depth.saturating_sub(1),
+                'U' if depth == 0 => {
+                    parts.push(s[start..index].trim());
+                    start = index + character.len_utf8();
+                }
+                _ => {}
+            }
+        }
+
+        parts.push(s[start..].trim());
+        parts
+    }
+
+    fn parse_single(s: &str, kind: Option<NumberKind>) -> Option<Self> {
+        match s {
+            s if s.starts_with('{') && s.ends_with('}') => Some(Self::parse_one_of_constraint(s)),
+            s if s.starts_with(['[', '(']) && s.ends_with([']', ')']) => {
+                Interval::parse(s, kind).map(Constraint::Interval)
+            }
+            _ => None,
+        }
+    }
+
+    fn parse_one_of_constraint(s: &str) -> Self {
+        let inner = &s[1..s.len() - 1];
+        let values = inner.split(',').map(|v| v.trim().to_string()).collect();
+        Self::OneOf(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(s: &str) -> Interval {
+        match Constraint::from(s) {
+            Constraint::Interval(interval) => interval,
+            constraint => panic!("expected an interval for '{}', got {:?}", s, constraint),
+        }
+    }
+
+    #[test]
+    fn parses_open_and_closed_bounds() {
+        let interval = interval("(0,1]");
+
+        assert_eq!(interval.lower(), Bound::Excluded(0.0));
+        assert_eq!(interval.upper(), Bound::Included(1.0));
+        assert!(!interval.contains(0.0));
+        assert!(interval.contains(1.0));
+    }
+
+    #[test]
+    fn parses_infinite_bounds() {
+        let interval = interval("[-inf,inf)");
+
+        assert_eq!(interval.lower(), Bound::Unbounded);
+        assert_eq!(interval.upper(), Bound::Unbounded);
+        assert!(interval.contains(f64::MIN));
+        assert!(interval.contains(f64::MAX));
+
+        assert_eq!(
+            self::interval("[0,inf)"),
+            Interval::new(NumberKind::Int, Bound::Included(0.0), Bound::Unbounded)
+        );
+        assert_eq!(
+            self::interval("(0,inf)"),
+            Interval::new(NumberKind::Int, Bound::Excluded(0.0), Bound::Unbounded)
+        );
+    }
+
+    #[test]
+    fn infers_kind_from_endpoints() {
+        let real = interval("[0.5,22050]");
+
+        assert_eq!(real.kind(), NumberKind::Real);
+        assert_eq!(real.lower(), Bound::Included(0.5));
+        assert_eq!(real.upper(), Bound::Included(22050.0));
+        assert!(real.contains(0.75));
+
+        let int = interval("[1,3]");
+
+        assert_eq!(int, Interval::int_range(1, 3));
+        assert!(int.contains(2.0));
+        assert!(!int.contains(2.5));
+        assert!(int.contains_int(3));
+        assert!(!int.contains_int(4));
+    }
+
+    #[test]
+    fn takes_kind_from_parameter_type() {
+        assert_eq!(
+            Constraint::for_parameter("(0,inf)", DataType::Float),
+            Constraint::Interval(Interval::positive_real())
+        );
+        assert_eq!(
+            Constraint::for_parameter("[0,inf)", DataType::Float),
+            Constraint::Interval(Interval::non_negative_real())
+        );
+        assert_eq!(
+            Constraint::for_parameter("[1,inf)", DataType::Int),
+            Constraint::Interval(Interval::positive_int())
+        );
+        assert_eq!(
+            Constraint::for_parameter("[0,inf)", DataType::VectorInt),
+            Constraint::Interval(Interval::non_negative_int())
+        );
+    }
+
+    #[test]
+    fn compares_reals_in_single_precision() {
+        let interval = interval("(0,0.1]");
+
+        assert!(interval.contains_real(0.1));
+        assert!(!interval.contains_real(0.2));
+        assert!(!interval.contains_real(0.0));
+    }
+
+    #[test]
+    fn parses_sets() {
+        let constraint = Constraint::from("{hann,hamming}");
+
+        assert_eq!(
+            constraint,
+            Constraint::OneOf(vec!["hann".to_string(), "hamming".to_string()])
+        );
+        assert!(constraint.allows_str("hann"));
+        assert!(!constraint.allows_str("blackmanharris62"));
+    }
+
+    #[test]
+    fn parses_unions() {
+        let constraint = Constraint::from("[0,inf) U {-1}");
+
+        assert_eq!(
+            constraint,
+            Constraint::Union(vec![
+                Constraint::Interval(Interval::non_negative_int()),
+                Constraint::OneOf(vec!["-1".to_string()]),
+            ])
+        );
+        assert!(constraint.allows_int(-1));
+        assert!(constraint.allows_int(10));
+        assert!(!constraint.allows_int(-2));
+        assert!(constraint.allows_real(-1.0));
+        assert!(!constraint.allows_real(-0.5));
+    }
+
+    #[test]
+    fn falls_back_to_custom() {
+        assert_eq!(Constraint::from(""), Constraint::Any);
+        assert_eq!(
+            Constraint::from("[0,inf"),
+            Constraint::Custom("[0,inf".to_string())
+        );
+        assert_eq!(
+            Constraint::from("(0,1] U nonsense"),
+            Constraint::Custom("(0,1] U nonsense".to_string())
+        );
+    }
+
+    #[test]
+    fn displays_in_essentia_notation() {
+        for constraint in ["(0,1]", "[0.5,22050]", "(-inf,inf)", "[0,inf) U {-1}"] {
+            assert_eq!(Constraint::from(constraint).to_string(), constraint);
+        }
+    }
+}
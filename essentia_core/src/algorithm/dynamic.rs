@@ -0,0 +1,160 @@
+use cxx::UniquePtr;
+use essentia_sys::ffi;
+use std::{collections::HashMap, marker::PhantomData};
+
+use crate::{
+    algorithm::{
+        ComputeError, ConfigurationError, DynamicComputeError, InputError, Introspection,
+        ParameterError, ResetError, algorithm::validate_parameter_data,
+    },
+    data::{DataContainer, Value, data_type},
+    essentia::Essentia,
+    parameter_map::ParameterMap,
+};
+
+/// An algorithm driven by names and [`Value`]s instead of marker types, for
+/// algorithms that are only known at runtime, e.g. from a configuration file.
+///
+/// Types and parameter constraints are checked against the [`Introspection`]
+/// when values are set. Parameters are applied by configuring the algorithm
+/// on the next [`DynamicAlgorithm::compute`], or explicitly through
+/// [`DynamicAlgorithm::configure`].
+pub struct DynamicAlgorithm<'a> {
+    algorithm_bridge: UniquePtr<ffi::AlgorithmBridge>,
+    introspection: Introspection,
+    parameters: HashMap<String, Value>,
+    configured: bool,
+    _marker: PhantomData<&'a Essentia>,
+}
+
+impl<'a> DynamicAlgorithm<'a> {
+    pub(crate) fn new(algorithm_bridge: UniquePtr<ffi::AlgorithmBridge>) -> Self {
+        let introspection = Introspection::from_algorithm_bridge(&algorithm_bridge);
+
+        Self {
+            algorithm_bridge,
+            introspection,
+            parameters: HashMap::new(),
+            configured: false,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn introspection(&self) -> &Introspection {
+        &self.introspection
+    }
+
+    /// Returns the parameters set so far, without the defaults of the
+    /// algorithm.
+    pub fn parameters(&self) -> &HashMap<String, Value> {
+        &self.parameters
+    }
+
+    pub fn set_parameter(&mut self, name: &str, value: Value) -> Result<(), ParameterError> {
+        let data_container = value.clone().into_data_container();
+
+        validate_parameter_data(
+            &self.introspection,
+            name,
+            value.data_type(),
+            data_container.inner.as_ref(),
+        )?;
+
+        self.parameters.insert(name.to_string(), value);
+        self.configured = false;
+
+        Ok(())
+    }
+
+    pub fn configure(&mut self) -> Result<(), ConfigurationError> {
+        let mut parameter_map = ParameterMap::new();
+
+        for (name, value) in &self.parameters {
+            parameter_map.set_parameter(name, value.clone().into_data_container());
+        }
+
+        self.algorithm_bridge
+            .pin_mut()
+            .configure(parameter_map.parameter_map_bridge)?;
+        self.configured = true;
+
+        Ok(())
+    }
+
+    pub fn set_input(&mut self, name: &str, value: Value) -> Result<(), InputError> {
+        let input_info =
+            self.introspection
+                .get_input(name)
+                .ok_or_else(|| InputError::InputNotFound {
+                    input: name.to_string(),
+                })?;
+
+        let expected_type = value.data_type();
+        let input_data_type = input_info.input_output_type();
+
+        if input_data_type != expected_type {
+            return Err(InputError::TypeMismatch {
+                input: name.to_string(),
+                expected: expected_type,
+                actual: input_data_type,
+            });
+        }
+
+        self.algorithm_bridge
+            .pin_mut()
+            .set_input(name, value.into_data_container().into_owned_ptr())
+            .unwrap_or_else(|_| panic!("failed to set input '{}' after validation", name));
+
+        Ok(())
+    }
+
+    /// Computes the algorithm, configuring it first if parameters changed,
+    /// and copies every output into a [`Value`].
+    pub fn compute(&mut self) -> Result<HashMap<String, Value>, DynamicComputeError> {
+        if !self.configured {
+            self.configure()?;
+        }
+
+        for output in self.introspection.outputs() {
+            self.algorithm_bridge
+                .pin_mut()
+                .setup_output(output.name(), output.input_output_type().into())
+                .unwrap_or_else(|_| {
+                    panic!(
+                        "failed to setup output '{}' after validation",
+                        output.name()
+                    )
+                });
+        }
+
+        self.algorithm_bridge
+            .pin_mut()
+            .compute()
+            .map_err(ComputeError::Compute)?;
+
+        let outputs = self
+            .introspection
+            .outputs()
+            .map(|output| {
+                let value = self
+                    .algorithm_bridge
+                    .get_output(output.name())
+                    .map(|data_container| {
+                        DataContainer::<data_type::Any>::new_borrowed(data_container).to_value()
+                    })
+                    .expect("failed to get output after validation");
+
+                (output.name().to_string(), value)
+            })
+            .collect();
+
+        Ok(outputs)
+    }
+
+    pub fn reset(&mut self) -> Result<(), ResetError> {
+        self.algorithm_bridge
+            .pin_mut()
+            .reset()
+            .map_err(ResetError::Internal)
+    }
+}
@@ -1,6 +1,6 @@
 use thiserror::Error;
 
-use crate::data::DataType;
+use crate::{algorithm::Constraint, data::DataType};
 
 #[derive(Debug, Error)]
 pub enum ParameterError {
@@ -13,6 +13,20 @@ pub enum ParameterError {
         expected: DataType,
         actual: DataType,
     },
+
+    #[error("Value {value} for parameter '{parameter}' violates constraint {constraint}")]
+    ConstraintViolation {
+        parameter: String,
+        constraint: Constraint,
+        value: String,
+    },
+
+    #[error("Failed to read parameter '{parameter}': {source}")]
+    Read {
+        parameter: String,
+        #[source]
+        source: cxx::Exception,
+    },
 }
 
 #[derive(Debug, Error)]
@@ -58,3 +72,12 @@ pub enum ResetError {
     #[error("Reset failed: {0}")]
     Internal(#[from] cxx::Exception),
 }
+
+#[derive(Debug, Error)]
+pub enum DynamicComputeError {
+    #[error("Configuration failed: {0}")]
+    Configuration(#[from] ConfigurationError),
+
+    #[error("Computation failed: {0}")]
+    Compute(#[from] ComputeError),
+}
@@ -1,8 +1,13 @@
 use std::collections::HashMap;
 
+use cxx::UniquePtr;
 use essentia_sys::ffi;
+use ndarray::Array2;
 
-use crate::data::DataType;
+use crate::{
+    algorithm::Constraint,
+    data::{DataContainer, DataType, Value, data_type},
+};
 
 #[derive(Debug, Clone)]
 pub struct Introspection {
@@ -15,6 +20,35 @@ pub struct Introspection {
 }
 
 impl Introspection {
+    /// Builds an introspection from already known metadata, without querying
+    /// Essentia.
+    pub fn new(
+        name: impl Into<String>,
+        category: impl Into<String>,
+        description: impl Into<String>,
+        inputs: impl IntoIterator<Item = InputOutputInfo>,
+        outputs: impl IntoIterator<Item = InputOutputInfo>,
+        parameters: impl IntoIterator<Item = ParameterInfo>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            category: category.into(),
+            description: description.into(),
+            input_infos: inputs
+                .into_iter()
+                .map(|info| (info.name.clone(), info))
+                .collect(),
+            output_infos: outputs
+                .into_iter()
+                .map(|info| (info.name.clone(), info))
+                .collect(),
+            parameter_infos: parameters
+                .into_iter()
+                .map(|info| (info.name.clone(), info))
+                .collect(),
+        }
+    }
+
     pub fn from_algorithm_bridge(algorithm_bridge: &ffi::AlgorithmBridge) -> Self {
         let input_info = algorithm_bridge
             .get_input_infos()
@@ -39,6 +73,10 @@ impl Introspection {
             .into_iter()
             .map(|info| {
                 let info: ParameterInfo = info.into();
+                let info = match algorithm_bridge.get_default_parameter(&info.name) {
+                    Ok(default_value) => info.with_typed_default_value(default_value),
+                    Err(_) => info,
+                };
                 (info.name.clone(), info)
             })
             .collect();
@@ -53,6 +91,52 @@ impl Introspection {
         }
     }
 
+    /// Builds the introspection of a streaming algorithm, where the sinks take
+    /// the place of the inputs and the sources the place of the outputs.
+    pub fn from_streaming_algorithm_bridge(
+        algorithm_bridge: &ffi::StreamingAlgorithmBridge,
+    ) -> Result<Self, cxx::Exception> {
+        let input_info = algorithm_bridge
+            .get_sink_infos()?
+            .into_iter()
+            .map(|info| {
+                let info: InputOutputInfo = info.into();
+                (info.name.clone(), info)
+            })
+            .collect();
+
+        let output_info = algorithm_bridge
+            .get_source_infos()?
+            .into_iter()
+            .map(|info| {
+                let info: InputOutputInfo = info.into();
+                (info.name.clone(), info)
+            })
+            .collect();
+
+        let parameter_info = algorithm_bridge
+            .get_parameter_infos()?
+            .into_iter()
+            .map(|info| {
+                let info: ParameterInfo = info.into();
+                let info = match algorithm_bridge.get_default_parameter(&info.name) {
+                    Ok(default_value) => info.with_typed_default_value(default_value),
+                    Err(_) => info,
+                };
+                (info.name.clone(), info)
+            })
+            .collect();
+
+        Ok(Self {
+            name: algorithm_bridge.get_name(),
+            category: algorithm_bridge.get_category(),
+            description: algorithm_bridge.get_description(),
+            input_infos: input_info,
+            output_infos: output_info,
+            parameter_infos: parameter_info,
+        })
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -91,6 +175,18 @@ pub struct InputOutputInfo {
 }
 
 impl InputOutputInfo {
+    pub fn new(
+        name: impl Into<String>,
+        data_type: DataType,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            data_type,
+            description: description.into(),
+        }
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -114,50 +210,6 @@ impl From<ffi::InputOutputInfo> for InputOutputInfo {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum Constraint {
-    Any,
-    PositiveReal,
-    NonNegativeReal,
-    IntRange { min: i32, max: i32 },
-    NonNegativeInt,
-    PositiveInt,
-    OneOf(Vec<String>),
-    Custom(String),
-}
-
-impl From<&str> for Constraint {
-    fn from(s: &str) -> Self {
-        if s.is_empty() {
-            return Constraint::Any;
-        }
-        match s {
-            "(0,inf)" => Constraint::PositiveReal,
-            "[0,inf)" => Constraint::NonNegativeReal,
-            s if s.starts_with('{') && s.ends_with('}') => Self::parse_one_of_constraint(s),
-            s if s.starts_with('[') && s.ends_with(']') => {
-                Self::parse_int_range_constraint(s).unwrap_or_else(|| Self::Custom(s.to_string()))
-            }
-            _ => Constraint::Custom(s.to_string()),
-        }
-    }
-}
-
-impl Constraint {
-    fn parse_one_of_constraint(s: &str) -> Self {
-        let inner = &s[1..s.len() - 1];
-        let values = inner.split(',').map(|v| v.trim().to_string()).collect();
-        Self::OneOf(values)
-    }
-    fn parse_int_range_constraint(s: &str) -> Option<Self> {
-        let inner = &s[1..s.len() - 1];
-        let (min_str, max_str) = inner.split_once(',')?;
-        let min = min_str.trim().parse::<i32>().ok()?;
-        let max = max_str.trim().parse::<i32>().ok()?;
-        Some(Self::IntRange { min, max })
-    }
-}
-
 #[derive(Debug, Clone)]
 pub struct ParameterInfo {
     name: String,
@@ -165,9 +217,78 @@ pub struct ParameterInfo {
     description: String,
     constraint: Constraint,
     default_value: String,
+    typed_default_value: Option<ParameterValue>,
+}
+
+macro_rules! parameter_value {
+    ($($variant:ident($type:ty)),* $(,)?) => {
+        /// A [`Value`] of one of the types parameters can hold. Unlike
+        /// `Value`, which can hold a pool, it owns no C++ memory, so
+        /// introspections built from a snapshot do not need the C++ bridge.
+        #[derive(Debug, Clone)]
+        enum ParameterValue {
+            $($variant($type)),*
+        }
+
+        impl ParameterValue {
+            fn from_value(value: Value) -> Option<Self> {
+                match value {
+                    $(Value::$variant(value) => Some(ParameterValue::$variant(value)),)*
+                    _ => None,
+                }
+            }
+
+            fn to_value(&self) -> Value {
+                match self {
+                    $(ParameterValue::$variant(value) => Value::$variant(value.clone()),)*
+                }
+            }
+        }
+    };
+}
+
+parameter_value! {
+    Bool(bool),
+    String(String),
+    Float(f32),
+    Int(i32),
+    StereoSample(ffi::StereoSample),
+    VectorBool(Vec<bool>),
+    VectorInt(Vec<i32>),
+    VectorString(Vec<String>),
+    VectorFloat(Vec<f32>),
+    VectorStereoSample(Vec<ffi::StereoSample>),
+    VectorVectorFloat(Vec<Vec<f32>>),
+    MatrixFloat(Array2<f32>),
+    VectorVectorString(Vec<Vec<String>>),
+    VectorVectorStereoSample(Vec<Vec<ffi::StereoSample>>),
+    VectorMatrixFloat(Vec<Array2<f32>>),
+    MapVectorFloat(HashMap<String, Vec<f32>>),
+    MapVectorString(HashMap<String, Vec<String>>),
+    MapVectorInt(HashMap<String, Vec<i32>>),
+    MapFloat(HashMap<String, f32>),
 }
 
 impl ParameterInfo {
+    /// Creates parameter metadata without a typed default value, which can
+    /// only be obtained from a live algorithm.
+    pub fn new(
+        name: impl Into<String>,
+        data_type: DataType,
+        description: impl Into<String>,
+        constraint: Constraint,
+        default_value: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            data_type,
+            description: description.into(),
+            constraint,
+            default_value: default_value.into(),
+            typed_default_value: None,
+        }
+    }
+
     pub fn name(&self) -> &str {
         &self.name
     }
@@ -183,6 +304,13 @@ impl ParameterInfo {
     pub fn default_value(&self) -> &str {
         &self.default_value
     }
+    /// Returns the default value as a data container, or `None` when the
+    /// parameter has no default or its type cannot be read back from Essentia.
+    pub fn typed_default_value(&self) -> Option<DataContainer<'static, data_type::Any>> {
+        self.typed_default_value
+            .as_ref()
+            .map(|default_value| default_value.to_value().into_data_container())
+    }
     pub fn optional(&self) -> bool {
         !self.default_value.is_empty()
     }
@@ -196,8 +324,33 @@ impl From<ffi::ParameterInfo> for ParameterInfo {
             name: value.name,
             data_type,
             description: value.description,
-            constraint: Constraint::from(value.constraint.as_str()),
+            constraint: Constraint::for_parameter(&value.constraint, data_type),
             default_value: value.default_value,
+            typed_default_value: None,
         }
     }
 }
+
+impl ParameterInfo {
+    fn with_typed_default_value(mut self, default_value: UniquePtr<ffi::DataContainer>) -> Self {
+        if !default_value.is_null() {
+            self.typed_default_value = ParameterValue::from_value(
+                DataContainer::<data_type::Any>::new_borrowed(&default_value).to_value(),
+            );
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn introspection_is_send_and_sync() {
+        assert_send_sync::<Introspection>();
+        assert_send_sync::<ParameterInfo>();
+    }
+}
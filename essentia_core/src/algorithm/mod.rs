@@ -1,7 +1,12 @@
 mod algorithm;
+mod constraint;
+mod dynamic;
 mod error;
 mod introspection;
 
+pub(crate) use algorithm::validate_parameter;
 pub use algorithm::{Algorithm, ComputeResult, Configured, Initialized};
+pub use constraint::{Constraint, Interval, NumberKind};
+pub use dynamic::DynamicAlgorithm;
 pub use error::*;
-pub use introspection::{Constraint, InputOutputInfo, Introspection, ParameterInfo};
+pub use introspection::{InputOutputInfo, Introspection, ParameterInfo};
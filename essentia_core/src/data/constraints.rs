@@ -6,6 +6,18 @@ pub trait InputOutputData: HasDataType {}
 
 pub trait PoolData: HasDataType {}
 
+/// Types that can be appended to a pool descriptor one value at a time,
+/// accumulating into the `Series` container type.
+pub trait PoolSeriesData: HasDataType {
+    type Series: PoolData;
+}
+
+/// Token types that can be fed into or collected from a streaming network
+/// as a whole sequence, stored in the `Sequence` container type.
+pub trait StreamData: HasDataType {
+    type Sequence: HasDataType;
+}
+
 pub trait ValidateConstraint<T> {
     const IS_VALID: bool;
 
@@ -73,6 +85,60 @@ impl PoolData for data_type::VectorFloat {}
 impl PoolData for data_type::VectorString {}
 impl PoolData for data_type::VectorStereoSample {}
 impl PoolData for data_type::TensorFloat {}
+impl PoolData for data_type::MatrixFloat {}
+impl PoolData for data_type::VectorVectorFloat {}
+impl PoolData for data_type::VectorVectorString {}
+impl PoolData for data_type::VectorMatrixFloat {}
+
+impl PoolSeriesData for data_type::Float {
+    type Series = data_type::VectorFloat;
+}
+impl PoolSeriesData for data_type::String {
+    type Series = data_type::VectorString;
+}
+impl PoolSeriesData for data_type::StereoSample {
+    type Series = data_type::VectorStereoSample;
+}
+impl PoolSeriesData for data_type::VectorFloat {
+    type Series = data_type::VectorVectorFloat;
+}
+impl PoolSeriesData for data_type::VectorString {
+    type Series = data_type::VectorVectorString;
+}
+impl PoolSeriesData for data_type::MatrixFloat {
+    type Series = data_type::VectorMatrixFloat;
+}
+
+impl StreamData for data_type::Float {
+    type Sequence = data_type::VectorFloat;
+}
+impl StreamData for data_type::Int {
+    type Sequence = data_type::VectorInt;
+}
+impl StreamData for data_type::String {
+    type Sequence = data_type::VectorString;
+}
+impl StreamData for data_type::StereoSample {
+    type Sequence = data_type::VectorStereoSample;
+}
+impl StreamData for data_type::Complex {
+    type Sequence = data_type::VectorComplex;
+}
+impl StreamData for data_type::VectorFloat {
+    type Sequence = data_type::VectorVectorFloat;
+}
+impl StreamData for data_type::VectorString {
+    type Sequence = data_type::VectorVectorString;
+}
+impl StreamData for data_type::VectorStereoSample {
+    type Sequence = data_type::VectorVectorStereoSample;
+}
+impl StreamData for data_type::VectorComplex {
+    type Sequence = data_type::VectorVectorComplex;
+}
+impl StreamData for data_type::MatrixFloat {
+    type Sequence = data_type::VectorMatrixFloat;
+}
 
 pub struct ParameterConstraint<T>(std::marker::PhantomData<T>);
 
@@ -3,7 +3,7 @@ use essentia_sys::ffi;
 use std::marker::PhantomData;
 use thiserror::Error;
 
-use super::types::{DataType, HasDataType};
+use super::types::{DataType, HasDataType, data_type};
 
 pub enum DataContainerInner<'a> {
     Owned(UniquePtr<ffi::DataContainer>),
@@ -39,7 +39,7 @@ impl<'a, T> DataContainer<'a, T> {
         }
     }
 
-    pub fn into_any(self) -> DataContainer<'a, super::types::data_type::Any> {
+    pub fn into_any(self) -> DataContainer<'a, data_type::Any> {
         DataContainer {
             inner: self.inner,
             _marker: PhantomData,
@@ -78,6 +78,19 @@ impl<'a, T: HasDataType> DataContainer<'a, T> {
     }
 }
 
+impl<'a> DataContainer<'a, data_type::Any> {
+    pub fn downcast<T: HasDataType>(self) -> Result<DataContainer<'a, T>, TypeMismatchError> {
+        let container = DataContainer {
+            inner: self.inner,
+            _marker: PhantomData,
+        };
+
+        container.verify_type()?;
+
+        Ok(container)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Error)]
 #[error("Type mismatch: expected {expected}, got {actual}")]
 pub struct TypeMismatchError {
@@ -85,7 +98,7 @@ pub struct TypeMismatchError {
     pub actual: DataType,
 }
 
-fn copy_to_owned(data: &ffi::DataContainer) -> UniquePtr<ffi::DataContainer> {
+pub(crate) fn copy_to_owned(data: &ffi::DataContainer) -> UniquePtr<ffi::DataContainer> {
     let data_type = data.get_data_type();
 
     match data_type {
@@ -117,6 +130,14 @@ fn copy_to_owned(data: &ffi::DataContainer) -> UniquePtr<ffi::DataContainer> {
             let value = data.get_stereo_sample().unwrap();
             ffi::create_data_container_from_stereo_sample(value)
         }
+        ffi::DataType::Complex => {
+            let value = data.get_complex().unwrap();
+            ffi::create_data_container_from_complex(value)
+        }
+        ffi::DataType::TensorFloat => {
+            let value = data.get_tensor_float().unwrap();
+            ffi::create_data_container_from_tensor_float(value)
+        }
         ffi::DataType::VectorBool => {
             let value = data.get_vector_bool().unwrap();
             ffi::create_data_container_from_vector_bool(&value)
@@ -138,6 +159,10 @@ fn copy_to_owned(data: &ffi::DataContainer) -> UniquePtr<ffi::DataContainer> {
             let value = data.get_vector_stereo_sample().unwrap();
             ffi::create_data_container_from_vector_stereo_sample(value)
         }
+        ffi::DataType::VectorComplex => {
+            let value = data.get_vector_complex().unwrap();
+            ffi::create_data_container_from_vector_complex(value)
+        }
         ffi::DataType::VectorVectorFloat => {
             let value = data.get_vector_vector_float().unwrap();
             ffi::create_data_container_from_vector_vector_float(value)
@@ -154,6 +179,10 @@ fn copy_to_owned(data: &ffi::DataContainer) -> UniquePtr<ffi::DataContainer> {
             let value = data.get_vector_vector_stereo_sample().unwrap();
             ffi::create_data_container_from_vector_vector_stereo_sample(value)
         }
+        ffi::DataType::VectorVectorComplex => {
+            let value = data.get_vector_vector_complex().unwrap();
+            ffi::create_data_container_from_vector_vector_complex(value)
+        }
         ffi::DataType::VectorMatrixFloat => {
             let value = data.get_vector_matrix_float().unwrap();
             ffi::create_data_container_from_vector_matrix_float(value)
@@ -170,6 +199,10 @@ fn copy_to_owned(data: &ffi::DataContainer) -> UniquePtr<ffi::DataContainer> {
             let value = data.get_map_vector_int().unwrap();
             ffi::create_data_container_from_map_vector_int(value)
         }
+        ffi::DataType::MapVectorComplex => {
+            let value = data.get_map_vector_complex().unwrap();
+            ffi::create_data_container_from_map_vector_complex(value)
+        }
         ffi::DataType::MapFloat => {
             let value = data.get_map_float().unwrap();
             ffi::create_data_container_from_map_float(value)
@@ -3,12 +3,20 @@ pub mod container;
 mod conversion_error;
 mod conversion_get;
 mod conversion_into;
+#[cfg(feature = "serde")]
+mod serde_impl;
 
 pub mod types;
+mod value;
 
-pub use constraints::{InputOutputData, ParameterData, PoolData, ValidateConstraint};
+pub use constraints::{
+    InputOutputData, ParameterData, PoolData, PoolSeriesData, StreamData, ValidateConstraint,
+};
 pub use container::DataContainer;
 pub use conversion_error::ConversionError;
 pub use conversion_get::{GetFromDataContainer, TryGetFromDataContainer};
 pub use conversion_into::{IntoDataContainer, TryIntoDataContainer};
-pub use types::{DataType, data_type};
+pub use types::{DataType, UnknownDataTypeError, data_type};
+pub use value::Value;
+
+pub use essentia_sys::ffi::StereoSample;
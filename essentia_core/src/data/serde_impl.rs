@@ -0,0 +1,229 @@
+//! Serde support for values and data containers, written like an enum
+//! variant named after the [`DataType`], e.g. `{"VectorFloat": [...]}`, so
+//! that the type survives formats that cannot tell e.g. `Int` and `Long`
+//! apart and does not depend on the order of map keys.
+
+use std::fmt;
+
+use essentia_sys::ffi;
+use once_cell::sync::Lazy;
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, DeserializeSeed, EnumAccess, VariantAccess, Visitor},
+};
+
+use crate::data::{DataContainer, DataType, Value, data_type};
+
+static VARIANTS: Lazy<Vec<&'static str>> =
+    Lazy::new(|| DataType::ALL.iter().map(DataType::as_str).collect());
+
+impl Serialize for DataType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for DataType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        name.parse().map_err(de::Error::custom)
+    }
+}
+
+fn stereo_to_tuple(sample: &ffi::StereoSample) -> (f32, f32) {
+    (sample.left, sample.right)
+}
+
+fn tuple_to_stereo((left, right): (f32, f32)) -> ffi::StereoSample {
+    ffi::StereoSample { left, right }
+}
+
+/// Serializes the content of a value without its type.
+struct ValuePayload<'a>(&'a Value);
+
+impl Serialize for ValuePayload<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Value::Bool(value) => value.serialize(serializer),
+            Value::String(value) => value.serialize(serializer),
+            Value::Float(value) => value.serialize(serializer),
+            Value::Int(value) => value.serialize(serializer),
+            Value::UnsignedInt(value) => value.serialize(serializer),
+            Value::Long(value) => value.serialize(serializer),
+            Value::StereoSample(value) => stereo_to_tuple(value).serialize(serializer),
+            Value::Complex(value) => value.serialize(serializer),
+            Value::TensorFloat(value) => value.serialize(serializer),
+            Value::VectorBool(value) => value.serialize(serializer),
+            Value::VectorInt(value) => value.serialize(serializer),
+            Value::VectorString(value) => value.serialize(serializer),
+            Value::VectorFloat(value) => value.serialize(serializer),
+            Value::VectorStereoSample(value) => {
+                let samples: Vec<(f32, f32)> = value.iter().map(stereo_to_tuple).collect();
+                samples.serialize(serializer)
+            }
+            Value::VectorComplex(value) => value.serialize(serializer),
+            Value::VectorVectorFloat(value) => value.serialize(serializer),
+            Value::MatrixFloat(value) => value.serialize(serializer),
+            Value::VectorVectorString(value) => value.serialize(serializer),
+            Value::VectorVectorStereoSample(value) => {
+                let samples: Vec<Vec<(f32, f32)>> = value
+                    .iter()
+                    .map(|row| row.iter().map(stereo_to_tuple).collect())
+                    .collect();
+                samples.serialize(serializer)
+            }
+            Value::VectorVectorComplex(value) => value.serialize(serializer),
+            Value::VectorMatrixFloat(value) => value.serialize(serializer),
+            Value::MapVectorFloat(value) => value.serialize(serializer),
+            Value::MapVectorString(value) => value.serialize(serializer),
+            Value::MapVectorInt(value) => value.serialize(serializer),
+            Value::MapVectorComplex(value) => value.serialize(serializer),
+            Value::MapFloat(value) => value.serialize(serializer),
+            Value::Pool(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl Serialize for Value {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let data_type = self.data_type();
+        let variant_index = DataType::ALL
+            .iter()
+            .position(|candidate| *candidate == data_type)
+            .expect("DataType::ALL should contain every data type");
+
+        serializer.serialize_newtype_variant(
+            "Value",
+            variant_index as u32,
+            data_type.as_str(),
+            &ValuePayload(self),
+        )
+    }
+}
+
+impl<'a, T> Serialize for DataContainer<'a, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_value().serialize(serializer)
+    }
+}
+
+/// Deserializes the variant tag of a value, which formats write either as the
+/// name or as the index of its data type.
+struct DataTypeTag;
+
+impl<'de> DeserializeSeed<'de> for DataTypeTag {
+    type Value = DataType;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for DataTypeTag {
+    type Value = DataType;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a data type")
+    }
+
+    fn visit_u64<E: de::Error>(self, index: u64) -> Result<Self::Value, E> {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| DataType::ALL.get(index).copied())
+            .ok_or_else(|| {
+                de::Error::invalid_value(de::Unexpected::Unsigned(index), &"a data type index")
+            })
+    }
+
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<Self::Value, E> {
+        name.parse()
+            .map_err(|_| de::Error::unknown_variant(name, &VARIANTS))
+    }
+}
+
+/// Deserializes the content of a value once its type is known.
+struct ValueSeed(DataType);
+
+impl<'de> DeserializeSeed<'de> for ValueSeed {
+    type Value = Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        macro_rules! value {
+            ($variant:ident) => {
+                Value::$variant(Deserialize::deserialize(deserializer)?)
+            };
+        }
+
+        let value = match self.0 {
+            DataType::Bool => value!(Bool),
+            DataType::String => value!(String),
+            DataType::Float => value!(Float),
+            DataType::Int => value!(Int),
+            DataType::UnsignedInt => value!(UnsignedInt),
+            DataType::Long => value!(Long),
+            DataType::StereoSample => {
+                Value::StereoSample(tuple_to_stereo(Deserialize::deserialize(deserializer)?))
+            }
+            DataType::Complex => value!(Complex),
+            DataType::TensorFloat => value!(TensorFloat),
+            DataType::VectorBool => value!(VectorBool),
+            DataType::VectorInt => value!(VectorInt),
+            DataType::VectorString => value!(VectorString),
+            DataType::VectorFloat => value!(VectorFloat),
+            DataType::VectorStereoSample => {
+                let samples: Vec<(f32, f32)> = Deserialize::deserialize(deserializer)?;
+                Value::VectorStereoSample(samples.into_iter().map(tuple_to_stereo).collect())
+            }
+            DataType::VectorComplex => value!(VectorComplex),
+            DataType::VectorVectorFloat => value!(VectorVectorFloat),
+            DataType::MatrixFloat => value!(MatrixFloat),
+            DataType::VectorVectorString => value!(VectorVectorString),
+            DataType::VectorVectorStereoSample => {
+                let samples: Vec<Vec<(f32, f32)>> = Deserialize::deserialize(deserializer)?;
+                Value::VectorVectorStereoSample(
+                    samples
+                        .into_iter()
+                        .map(|row| row.into_iter().map(tuple_to_stereo).collect())
+                        .collect(),
+                )
+            }
+            DataType::VectorVectorComplex => value!(VectorVectorComplex),
+            DataType::VectorMatrixFloat => value!(VectorMatrixFloat),
+            DataType::MapVectorFloat => value!(MapVectorFloat),
+            DataType::MapVectorString => value!(MapVectorString),
+            DataType::MapVectorInt => value!(MapVectorInt),
+            DataType::MapVectorComplex => value!(MapVectorComplex),
+            DataType::MapFloat => value!(MapFloat),
+            DataType::Pool => value!(Pool),
+        };
+
+        Ok(value)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a value tagged with its data type")
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        let (data_type, variant) = data.variant_seed(DataTypeTag)?;
+        variant.newtype_variant_seed(ValueSeed(data_type))
+    }
+}
+
+impl<'de> Deserialize<'de> for Value {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_enum("Value", &VARIANTS, ValueVisitor)
+    }
+}
+
+impl<'de> Deserialize<'de> for DataContainer<'static, data_type::Any> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Value::deserialize(deserializer).map(Value::into_data_container)
+    }
+}
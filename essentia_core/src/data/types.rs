@@ -1,5 +1,6 @@
 use essentia_sys::ffi;
-use std::fmt;
+use std::{fmt, str::FromStr};
+use thiserror::Error;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DataType {
@@ -33,6 +34,36 @@ pub enum DataType {
 }
 
 impl DataType {
+    pub const ALL: [DataType; 27] = [
+        DataType::Float,
+        DataType::String,
+        DataType::Bool,
+        DataType::Int,
+        DataType::UnsignedInt,
+        DataType::Long,
+        DataType::StereoSample,
+        DataType::Complex,
+        DataType::TensorFloat,
+        DataType::VectorFloat,
+        DataType::VectorString,
+        DataType::VectorBool,
+        DataType::VectorInt,
+        DataType::VectorStereoSample,
+        DataType::VectorComplex,
+        DataType::VectorVectorFloat,
+        DataType::VectorVectorString,
+        DataType::VectorVectorStereoSample,
+        DataType::VectorVectorComplex,
+        DataType::VectorMatrixFloat,
+        DataType::MapVectorFloat,
+        DataType::MapVectorString,
+        DataType::MapVectorInt,
+        DataType::MapVectorComplex,
+        DataType::MapFloat,
+        DataType::MatrixFloat,
+        DataType::Pool,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             DataType::Float => "Float",
@@ -66,6 +97,21 @@ impl DataType {
     }
 }
 
+#[derive(Debug, Error)]
+#[error("Unknown data type '{0}'")]
+pub struct UnknownDataTypeError(pub String);
+
+impl FromStr for DataType {
+    type Err = UnknownDataTypeError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        DataType::ALL
+            .into_iter()
+            .find(|data_type| data_type.as_str() == name)
+            .ok_or_else(|| UnknownDataTypeError(name.to_string()))
+    }
+}
+
 impl fmt::Display for DataType {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.as_str())
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use essentia_sys::ffi;
+use ndarray::{Array, Array2, Array4, Dimension};
+
+use crate::{
+    data::{DataContainer, DataType, GetFromDataContainer, IntoDataContainer, data_type},
+    pool::Pool,
+};
+
+/// An owned value of any [`DataType`], for code that only learns the type of
+/// a value at runtime.
+#[derive(Debug, Clone)]
+pub enum Value {
+    Bool(bool),
+    String(String),
+    Float(f32),
+    Int(i32),
+    UnsignedInt(u32),
+    Long(i64),
+    StereoSample(ffi::StereoSample),
+    Complex(num::Complex<f32>),
+    TensorFloat(Array4<f32>),
+    VectorBool(Vec<bool>),
+    VectorInt(Vec<i32>),
+    VectorString(Vec<String>),
+    VectorFloat(Vec<f32>),
+    VectorStereoSample(Vec<ffi::StereoSample>),
+    VectorComplex(Vec<num::Complex<f32>>),
+    VectorVectorFloat(Vec<Vec<f32>>),
+    MatrixFloat(Array2<f32>),
+    VectorVectorString(Vec<Vec<String>>),
+    VectorVectorStereoSample(Vec<Vec<ffi::StereoSample>>),
+    VectorVectorComplex(Vec<Vec<num::Complex<f32>>>),
+    VectorMatrixFloat(Vec<Array2<f32>>),
+    MapVectorFloat(HashMap<String, Vec<f32>>),
+    MapVectorString(HashMap<String, Vec<String>>),
+    MapVectorInt(HashMap<String, Vec<i32>>),
+    MapVectorComplex(HashMap<String, Vec<num::Complex<f32>>>),
+    MapFloat(HashMap<String, f32>),
+    Pool(Pool),
+}
+
+impl Value {
+    pub fn data_type(&self) -> DataType {
+        match self {
+            Value::Bool(_) => DataType::Bool,
+            Value::String(_) => DataType::String,
+            Value::Float(_) => DataType::Float,
+            Value::Int(_) => DataType::Int,
+            Value::UnsignedInt(_) => DataType::UnsignedInt,
+            Value::Long(_) => DataType::Long,
+            Value::StereoSample(_) => DataType::StereoSample,
+            Value::Complex(_) => DataType::Complex,
+            Value::TensorFloat(_) => DataType::TensorFloat,
+            Value::VectorBool(_) => DataType::VectorBool,
+            Value::VectorInt(_) => DataType::VectorInt,
+            Value::VectorString(_) => DataType::VectorString,
+            Value::VectorFloat(_) => DataType::VectorFloat,
+            Value::VectorStereoSample(_) => DataType::VectorStereoSample,
+            Value::VectorComplex(_) => DataType::VectorComplex,
+            Value::VectorVectorFloat(_) => DataType::VectorVectorFloat,
+            Value::MatrixFloat(_) => DataType::MatrixFloat,
+            Value::VectorVectorString(_) => DataType::VectorVectorString,
+            Value::VectorVectorStereoSample(_) => DataType::VectorVectorStereoSample,
+            Value::VectorVectorComplex(_) => DataType::VectorVectorComplex,
+            Value::VectorMatrixFloat(_) => DataType::VectorMatrixFloat,
+            Value::MapVectorFloat(_) => DataType::MapVectorFloat,
+            Value::MapVectorString(_) => DataType::MapVectorString,
+            Value::MapVectorInt(_) => DataType::MapVectorInt,
+            Value::MapVectorComplex(_) => DataType::MapVectorComplex,
+            Value::MapFloat(_) => DataType::MapFloat,
+            Value::Pool(_) => DataType::Pool,
+        }
+    }
+
+    pub fn into_data_container(self) -> DataContainer<'static, data_type::Any> {
+        match self {
+            Value::Bool(value) => value.into_data_container().into_any(),
+            Value::String(value) => value.as_str().into_data_container().into_any(),
+            Value::Float(value) => value.into_data_container().into_any(),
+            Value::Int(value) => value.into_data_container().into_any(),
+            Value::UnsignedInt(value) => value.into_data_container().into_any(),
+            Value::Long(value) => value.into_data_container().into_any(),
+            Value::StereoSample(value) => value.into_data_container().into_any(),
+            Value::Complex(value) => value.into_data_container().into_any(),
+            Value::TensorFloat(value) => (&standard_layout(value)).into_data_container().into_any(),
+            Value::VectorBool(value) => value.as_slice().into_data_container().into_any(),
+            Value::VectorInt(value) => value.as_slice().into_data_container().into_any(),
+            Value::VectorString(value) => {
+                let strings: Vec<&str> = value.iter().map(String::as_str).collect();
+                strings.as_slice().into_data_container().into_any()
+            }
+            Value::VectorFloat(value) => value.as_slice().into_data_container().into_any(),
+            Value::VectorStereoSample(value) => value.as_slice().into_data_container().into_any(),
+            Value::VectorComplex(value) => value.as_slice().into_data_container().into_any(),
+            Value::VectorVectorFloat(value) => value.as_slice().into_data_container().into_any(),
+            Value::MatrixFloat(value) => (&standard_layout(value)).into_data_container().into_any(),
+            Value::VectorVectorString(value) => {
+                let rows: Vec<Vec<&str>> = value
+                    .iter()
+                    .map(|row| row.iter().map(String::as_str).collect())
+                    .collect();
+                let rows: Vec<&[&str]> = rows.iter().map(Vec::as_slice).collect();
+                rows.as_slice().into_data_container().into_any()
+            }
+            Value::VectorVectorStereoSample(value) => {
+                let rows: Vec<&[ffi::StereoSample]> = value.iter().map(Vec::as_slice).collect();
+                rows.as_slice().into_data_container().into_any()
+            }
+            Value::VectorVectorComplex(value) => value.as_slice().into_data_container().into_any(),
+            Value::VectorMatrixFloat(value) => {
+                let matrices: Vec<Array2<f32>> = value.into_iter().map(standard_layout).collect();
+                matrices.as_slice().into_data_container().into_any()
+            }
+            Value::MapVectorFloat(value) => (&value).into_data_container().into_any(),
+            Value::MapVectorString(value) => (&value).into_data_container().into_any(),
+            Value::MapVectorInt(value) => (&value).into_data_container().into_any(),
+            Value::MapVectorComplex(value) => (&value).into_data_container().into_any(),
+            Value::MapFloat(value) => (&value).into_data_container().into_any(),
+            Value::Pool(value) => value.into_data_container().into_any(),
+        }
+    }
+}
+
+/// The conversions into data containers copy from a contiguous slice, so
+/// arrays in a different memory order are rearranged first.
+fn standard_layout<D: Dimension>(array: Array<f32, D>) -> Array<f32, D> {
+    if array.is_standard_layout() {
+        array
+    } else {
+        array.as_standard_layout().into_owned()
+    }
+}
+
+impl<'a, T> DataContainer<'a, T> {
+    /// Copies the content of the container into a [`Value`] matching its
+    /// runtime data type.
+    pub fn to_value(&self) -> Value {
+        let inner = self.inner.as_ref();
+
+        macro_rules! get {
+            ($marker:ident) => {
+                Value::$marker(DataContainer::<data_type::$marker>::new_borrowed(inner).get())
+            };
+        }
+
+        match self.data_type() {
+            DataType::Bool => get!(Bool),
+            DataType::String => get!(String),
+            DataType::Float => get!(Float),
+            DataType::Int => get!(Int),
+            DataType::UnsignedInt => get!(UnsignedInt),
+            DataType::Long => get!(Long),
+            DataType::StereoSample => get!(StereoSample),
+            DataType::Complex => get!(Complex),
+            DataType::TensorFloat => get!(TensorFloat),
+            DataType::VectorBool => get!(VectorBool),
+            DataType::VectorInt => get!(VectorInt),
+            DataType::VectorString => get!(VectorString),
+            DataType::VectorFloat => get!(VectorFloat),
+            DataType::VectorStereoSample => get!(VectorStereoSample),
+            DataType::VectorComplex => get!(VectorComplex),
+            DataType::VectorVectorFloat => get!(VectorVectorFloat),
+            DataType::MatrixFloat => get!(MatrixFloat),
+            DataType::VectorVectorString => get!(VectorVectorString),
+            DataType::VectorVectorStereoSample => get!(VectorVectorStereoSample),
+            DataType::VectorVectorComplex => get!(VectorVectorComplex),
+            DataType::VectorMatrixFloat => get!(VectorMatrixFloat),
+            DataType::MapVectorFloat => get!(MapVectorFloat),
+            DataType::MapVectorString => get!(MapVectorString),
+            DataType::MapVectorInt => get!(MapVectorInt),
+            DataType::MapVectorComplex => get!(MapVectorComplex),
+            DataType::MapFloat => get!(MapFloat),
+            DataType::Pool => get!(Pool),
+        }
+    }
+}
+
+impl From<DataContainer<'_, data_type::Any>> for Value {
+    fn from(data_container: DataContainer<'_, data_type::Any>) -> Self {
+        data_container.to_value()
+    }
+}
@@ -4,4 +4,11 @@ use thiserror::Error;
 pub enum CreateAlgorithmError {
     #[error("algorithm not found: {name}")]
     AlgorithmNotFound { name: String },
+
+    #[error("failed to create algorithm {name}: {source}")]
+    Internal {
+        name: String,
+        #[source]
+        source: cxx::Exception,
+    },
 }
@@ -7,8 +7,9 @@ use essentia_sys::ffi;
 use once_cell::sync::Lazy;
 
 use crate::{
-    algorithm::{Algorithm, Initialized},
+    algorithm::{Algorithm, DynamicAlgorithm, Initialized},
     essentia::error::CreateAlgorithmError,
+    streaming::StreamingAlgorithm,
 };
 
 static GLOBAL_LIFECYCLE: Lazy<Mutex<Weak<EssentiaLifecycle>>> =
@@ -17,6 +18,9 @@ static GLOBAL_LIFECYCLE: Lazy<Mutex<Weak<EssentiaLifecycle>>> =
 static AVAILABLE_ALGORITHMS: Lazy<HashSet<String>> =
     Lazy::new(|| ffi::get_algorithm_names().into_iter().collect());
 
+static AVAILABLE_STREAMING_ALGORITHMS: Lazy<HashSet<String>> =
+    Lazy::new(|| ffi::get_streaming_algorithm_names().into_iter().collect());
+
 struct EssentiaLifecycle {}
 
 impl EssentiaLifecycle {
@@ -83,6 +87,48 @@ impl Essentia {
 
         Ok(Algorithm::new(algorithm_bridge))
     }
+
+    /// Creates an algorithm whose parameters, inputs and outputs are handled
+    /// as [`Value`](crate::data::Value)s and checked at runtime.
+    pub fn create_dynamic_algorithm<'a>(
+        &'a self,
+        algorithm_name: &str,
+    ) -> Result<DynamicAlgorithm<'a>, CreateAlgorithmError> {
+        if !AVAILABLE_ALGORITHMS.contains(algorithm_name) {
+            return Err(CreateAlgorithmError::AlgorithmNotFound {
+                name: algorithm_name.to_string(),
+            });
+        }
+
+        ffi::create_algorithm_bridge(algorithm_name)
+            .map(DynamicAlgorithm::new)
+            .map_err(|source| CreateAlgorithmError::Internal {
+                name: algorithm_name.to_string(),
+                source,
+            })
+    }
+
+    pub fn available_streaming_algorithms(&self) -> impl Iterator<Item = &str> {
+        AVAILABLE_STREAMING_ALGORITHMS.iter().map(|s| s.as_str())
+    }
+
+    pub fn create_streaming_algorithm<'a>(
+        &'a self,
+        algorithm_name: &str,
+    ) -> Result<StreamingAlgorithm<'a, Initialized>, CreateAlgorithmError> {
+        if !AVAILABLE_STREAMING_ALGORITHMS.contains(algorithm_name) {
+            return Err(CreateAlgorithmError::AlgorithmNotFound {
+                name: algorithm_name.to_string(),
+            });
+        }
+
+        ffi::create_streaming_algorithm_bridge(algorithm_name)
+            .and_then(StreamingAlgorithm::new)
+            .map_err(|source| CreateAlgorithmError::Internal {
+                name: algorithm_name.to_string(),
+                source,
+            })
+    }
 }
 
 impl Clone for Essentia {
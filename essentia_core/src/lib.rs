@@ -10,6 +10,7 @@ pub mod algorithm;
 pub mod essentia;
 pub mod parameter_map;
 pub mod pool;
+pub mod streaming;
 
 // ==============================================================================
 // RE-EXPORTS - CLEAN API
@@ -17,15 +18,18 @@ pub mod pool;
 
 // Core data types with compile-time constraints
 pub use data::{ConversionError, GetFromDataContainer, IntoDataContainer};
-pub use data::{DataContainer, DataType, data_type};
-pub use data::{InputOutputData, ParameterData, PoolData};
+pub use data::{DataContainer, DataType, Value, data_type};
+pub use data::{InputOutputData, ParameterData, PoolData, PoolSeriesData, StreamData};
 
 // Algorithm and execution
-pub use algorithm::{Algorithm, Configured, Initialized, Introspection};
+pub use algorithm::{Algorithm, Configured, DynamicAlgorithm, Initialized, Introspection};
 pub use essentia::{CreateAlgorithmError, Essentia};
-pub use pool::{Pool, PoolError};
+pub use pool::{AggregationSpec, MergeType, Pool, PoolError, Statistic};
+pub use streaming::{Network, StreamingAlgorithm};
 
 // Error types
 pub use algorithm::{
-    ComputeError, ConfigurationError, InputError, OutputError, ParameterError, ResetError,
+    ComputeError, ConfigurationError, DynamicComputeError, InputError, OutputError, ParameterError,
+    ResetError,
 };
+pub use streaming::{ConnectionError, NetworkError};
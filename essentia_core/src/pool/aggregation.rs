@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::{
+    algorithm::{ComputeError, ConfigurationError, InputError, OutputError, ParameterError},
+    data::{GetFromDataContainer, data_type},
+    essentia::{CreateAlgorithmError, Essentia},
+    pool::{Pool, PoolError},
+};
+
+/// A statistic computed by [`Pool::aggregate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Statistic {
+    Min,
+    Max,
+    Median,
+    Mean,
+    Var,
+    Stdev,
+    Skew,
+    Kurt,
+    /// Mean of the first derivative
+    Dmean,
+    /// Variance of the first derivative
+    Dvar,
+    /// Mean of the second derivative
+    Dmean2,
+    /// Variance of the second derivative
+    Dvar2,
+    /// Covariance matrix, only for vector descriptors
+    Cov,
+    /// Inverse covariance matrix, only for vector descriptors
+    Icov,
+    /// Single values are passed through unchanged
+    Value,
+    /// Copies the whole series to the output
+    Copy,
+    /// The last value of the series
+    Last,
+}
+
+impl Statistic {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Statistic::Min => "min",
+            Statistic::Max => "max",
+            Statistic::Median => "median",
+            Statistic::Mean => "mean",
+            Statistic::Var => "var",
+            Statistic::Stdev => "stdev",
+            Statistic::Skew => "skew",
+            Statistic::Kurt => "kurt",
+            Statistic::Dmean => "dmean",
+            Statistic::Dvar => "dvar",
+            Statistic::Dmean2 => "dmean2",
+            Statistic::Dvar2 => "dvar2",
+            Statistic::Cov => "cov",
+            Statistic::Icov => "icov",
+            Statistic::Value => "value",
+            Statistic::Copy => "copy",
+            Statistic::Last => "last",
+        }
+    }
+}
+
+/// Selects the statistics [`Pool::aggregate`] computes.
+///
+/// Keys without explicit statistics get the default ones, which are
+/// Essentia's own defaults unless overridden. Exclusion patterns match whole
+/// keys, where `*` stands for any sequence of characters, so `lowlevel.*`
+/// skips a whole namespace.
+#[derive(Debug, Clone, Default)]
+pub struct AggregationSpec {
+    default_stats: Option<Vec<Statistic>>,
+    stats: HashMap<String, Vec<Statistic>>,
+    exclude: Vec<String>,
+}
+
+impl AggregationSpec {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn default_stats(mut self, stats: impl IntoIterator<Item = Statistic>) -> Self {
+        self.default_stats = Some(stats.into_iter().collect());
+        self
+    }
+
+    pub fn stats(mut self, key: &str, stats: impl IntoIterator<Item = Statistic>) -> Self {
+        self.stats
+            .insert(key.to_string(), stats.into_iter().collect());
+        self
+    }
+
+    pub fn exclude(mut self, pattern: &str) -> Self {
+        self.exclude.push(pattern.to_string());
+        self
+    }
+
+    pub fn is_excluded(&self, key: &str) -> bool {
+        self.exclude
+            .iter()
+            .any(|pattern| matches_pattern(pattern, key))
+    }
+}
+
+fn stat_names(stats: &[Statistic]) -> Vec<String> {
+    stats.iter().map(|stat| stat.as_str().to_string()).collect()
+}
+
+fn matches_pattern(pattern: &str, key: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == key,
+        Some((prefix, rest)) => {
+            let Some(key) = key.strip_prefix(prefix) else {
+                return false;
+            };
+
+            key.char_indices()
+                .map(|(index, _)| index)
+                .chain(std::iter::once(key.len()))
+                .any(|index| matches_pattern(rest, &key[index..]))
+        }
+    }
+}
+
+/// Aggregation wraps Essentia's `PoolAggregator`, which extractors use to
+/// summarize frame-wise descriptors.
+impl Pool {
+    pub fn aggregate(&self, spec: &AggregationSpec) -> Result<Pool, AggregationError> {
+        let mut input = self.clone();
+
+        for key in self.keys() {
+            if spec.is_excluded(&key) {
+                input.remove(&key)?;
+            }
+        }
+
+        let essentia = Essentia::new();
+        let mut algorithm = essentia.create_algorithm("PoolAggregator")?;
+
+        if let Some(default_stats) = &spec.default_stats {
+            let names = stat_names(default_stats);
+            let names: Vec<&str> = names.iter().map(String::as_str).collect();
+            algorithm.set_parameter::<data_type::VectorString>("defaultStats", names.as_slice())?;
+        }
+
+        if !spec.stats.is_empty() {
+            let exceptions: HashMap<String, Vec<String>> = spec
+                .stats
+                .iter()
+                .map(|(key, stats)| (key.clone(), stat_names(stats)))
+                .collect();
+            algorithm.set_parameter::<data_type::MapVectorString>("exceptions", &exceptions)?;
+        }
+
+        let mut algorithm = algorithm.configure()?;
+        algorithm.set_input::<data_type::Pool>("input", input)?;
+
+        let result = algorithm.compute()?;
+        let output = result.output::<data_type::Pool>("output")?.get();
+
+        Ok(output)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum AggregationError {
+    #[error("Failed to create algorithm: {0}")]
+    Create(#[from] CreateAlgorithmError),
+
+    #[error("Failed to set parameter: {0}")]
+    Parameter(#[from] ParameterError),
+
+    #[error("Failed to configure algorithm: {0}")]
+    Configuration(#[from] ConfigurationError),
+
+    #[error("Failed to set input: {0}")]
+    Input(#[from] InputError),
+
+    #[error("Failed to compute: {0}")]
+    Compute(#[from] ComputeError),
+
+    #[error("Failed to get output: {0}")]
+    Output(#[from] OutputError),
+
+    #[error("Failed to prepare input pool: {0}")]
+    Pool(#[from] PoolError),
+}
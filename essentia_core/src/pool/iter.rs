@@ -0,0 +1,71 @@
+use std::vec;
+
+use crate::data::{DataContainer, DataType, data_type};
+
+use super::{Pool, PoolError};
+
+/// Iterator over the descriptors of a [`Pool`], created by [`Pool::iter`]
+/// and [`Pool::iter_prefix`].
+///
+/// The keys are collected when the iterator is created, and each value is
+/// copied out of the pool as it is reached. Series of tensors, which have no
+/// `DataType` counterpart, are skipped; any other failure to read a value is
+/// yielded as an error.
+pub struct Iter<'a> {
+    pool: &'a Pool,
+    keys: vec::IntoIter<String>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = Result<(String, DataType, DataContainer<'static, data_type::Any>), PoolError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for key in self.keys.by_ref() {
+            match self.pool.get_any(&key) {
+                Ok(data_container) => {
+                    let data_type = data_container.data_type();
+                    return Some(Ok((key, data_type, data_container)));
+                }
+                Err(PoolError::TensorSeries { .. }) => continue,
+                Err(error) => return Some(Err(error)),
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.keys.len()))
+    }
+}
+
+impl Pool {
+    /// Iterates over the descriptors with their types and values.
+    ///
+    /// Series of tensors, the only descriptors without a [`DataType`], are
+    /// skipped. Use [`Pool::keys`] to list every descriptor. Any other value
+    /// that cannot be copied out of the pool is yielded as an error.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            pool: self,
+            keys: self.keys().into_iter(),
+        }
+    }
+
+    /// Like [`Pool::iter`], restricted to the keys starting with `prefix`.
+    pub fn iter_prefix(&self, prefix: &str) -> Iter<'_> {
+        Iter {
+            pool: self,
+            keys: self.keys_with_prefix(prefix).into_iter(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Pool {
+    type Item = Result<(String, DataType, DataContainer<'static, data_type::Any>), PoolError>;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
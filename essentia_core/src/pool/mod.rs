@@ -1,3 +1,15 @@
+mod aggregation;
+mod iter;
 mod pool;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "yaml")]
+mod serialization;
+mod tree;
 
-pub use pool::{Pool, PoolError};
+pub use aggregation::{AggregationError, AggregationSpec, Statistic};
+pub use iter::Iter;
+pub use pool::{MergeType, Pool, PoolError};
+#[cfg(feature = "yaml")]
+pub use serialization::PoolSerializationError;
+pub use tree::KeyTree;
@@ -1,15 +1,59 @@
 use cxx::UniquePtr;
 use essentia_sys::ffi;
+use std::fmt;
 use thiserror::Error;
 
 use crate::IntoDataContainer;
 use crate::data::types::HasDataType;
-use crate::data::{DataContainer, DataType, GetFromDataContainer, PoolData};
+use crate::data::{
+    DataContainer, DataType, GetFromDataContainer, PoolData, PoolSeriesData, data_type,
+};
+
+/// How [`Pool::merge`] handles descriptors present in both pools.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeType {
+    /// Fail if a descriptor already exists
+    #[default]
+    Disjoint,
+    /// Overwrite existing descriptors
+    Replace,
+    /// Append the other pool's values to existing series
+    Append,
+    /// Interleave the values of series descriptors
+    Interleave,
+}
+
+impl MergeType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MergeType::Disjoint => "",
+            MergeType::Replace => "replace",
+            MergeType::Append => "append",
+            MergeType::Interleave => "interleave",
+        }
+    }
+}
 
 pub struct Pool {
     inner: UniquePtr<ffi::PoolBridge>,
 }
 
+// SAFETY: the bridge exclusively owns its `essentia::Pool`, which keeps no
+// thread-local state, so the pool can be moved to another thread.
+unsafe impl Send for Pool {}
+
+impl Clone for Pool {
+    fn clone(&self) -> Self {
+        Self::new_from_bridge(self.inner.as_ref().unwrap().clone())
+    }
+}
+
+impl fmt::Debug for Pool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pool").field("keys", &self.keys()).finish()
+    }
+}
+
 impl Default for Pool {
     fn default() -> Self {
         Self::new()
@@ -27,17 +71,93 @@ impl Pool {
         Self { inner: bridge }
     }
 
+    /// Stores a value under `key`, replacing any previous value.
+    ///
+    /// Essentia's pool has no single-value slot for `StereoSample` and
+    /// `MatrixFloat`, so setting them fails with
+    /// [`PoolError::UnsupportedType`]; add them to a series with
+    /// [`Pool::add`] instead. Values of the series types
+    /// `VectorStereoSample`, `VectorVectorFloat`, `VectorVectorString` and
+    /// `VectorMatrixFloat` are stored element by element, so setting them to
+    /// an empty series fails and keeps the previous value.
     pub fn set<T>(&mut self, key: &str, value: impl IntoDataContainer<T>) -> Result<(), PoolError>
     where
         T: PoolData + HasDataType,
     {
-        let data_container = value.into_data_container();
+        self.set_any(key, value.into_data_container().into_any())
+    }
+
+    /// Appends a value to the series stored under `key`, like Essentia's
+    /// frame-wise extractors do. Read the accumulated values back with
+    /// [`Pool::get_series`].
+    pub fn add<T>(&mut self, key: &str, value: impl IntoDataContainer<T>) -> Result<(), PoolError>
+    where
+        T: PoolSeriesData,
+    {
+        self.add_any(key, value.into_data_container().into_any())
+    }
+
+    pub(crate) fn add_any(
+        &mut self,
+        key: &str,
+        data_container: DataContainer<'_, data_type::Any>,
+    ) -> Result<(), PoolError> {
+        self.inner
+            .pin_mut()
+            .add(key, data_container.into_owned_ptr())
+            .map_err(|exception| PoolError::Internal {
+                key: key.to_string(),
+                source: exception,
+            })
+    }
+
+    /// Reads back the series accumulated with [`Pool::add`], typed by the
+    /// element type.
+    pub fn get_series<T, R>(&self, key: &str) -> Result<R, PoolError>
+    where
+        T: PoolSeriesData,
+        for<'a> DataContainer<'a, T::Series>: GetFromDataContainer<R>,
+    {
+        self.get::<T::Series, R>(key)
+    }
+
+    pub fn merge(&mut self, other: &Pool, merge_type: MergeType) -> Result<(), PoolError> {
+        self.inner
+            .pin_mut()
+            .merge(other.inner.as_ref().unwrap(), merge_type.as_str())
+            .map_err(|exception| PoolError::Merge { source: exception })
+    }
+
+    pub fn remove(&mut self, key: &str) -> Result<(), PoolError> {
+        if !self.contains(key) {
+            return Err(PoolError::KeyNotFound {
+                key: key.to_string(),
+            });
+        }
+
+        self.inner
+            .pin_mut()
+            .remove(key)
+            .map_err(|exception| PoolError::Internal {
+                key: key.to_string(),
+                source: exception,
+            })
+    }
 
+    /// Removes every descriptor below `namespace`, e.g. `lowlevel` removes
+    /// `lowlevel.mfcc` and `lowlevel.spectral.centroid`.
+    pub fn remove_namespace(&mut self, namespace: &str) -> Result<(), PoolError> {
         self.inner
             .pin_mut()
-            .set(key, data_container.into_owned_ptr());
+            .remove_namespace(namespace)
+            .map_err(|exception| PoolError::Internal {
+                key: namespace.to_string(),
+                source: exception,
+            })
+    }
 
-        Ok(())
+    pub fn clear(&mut self) {
+        self.inner.pin_mut().clear();
     }
 
     pub fn get<T, R>(&self, key: &str) -> Result<R, PoolError>
@@ -51,6 +171,12 @@ impl Pool {
             });
         }
 
+        if self.inner.as_ref().unwrap().is_tensor_series(key) {
+            return Err(PoolError::TensorSeries {
+                key: key.to_string(),
+            });
+        }
+
         let data_container_ffi =
             self.inner
                 .as_ref()
@@ -82,6 +208,29 @@ impl Pool {
     where
         T: PoolData + HasDataType,
     {
+        let data_container = self.get_any(key)?;
+
+        // Verify type safety
+        let expected_type = T::data_type();
+        let actual_type = data_container.data_type();
+
+        if actual_type != expected_type {
+            return Err(PoolError::TypeMismatch {
+                key: key.to_string(),
+                expected: expected_type,
+                actual: actual_type,
+            });
+        }
+
+        Ok(data_container
+            .downcast()
+            .expect("data type was checked above"))
+    }
+
+    pub(crate) fn get_any(
+        &self,
+        key: &str,
+    ) -> Result<DataContainer<'static, data_type::Any>, PoolError> {
         if !self.contains(key) {
             return Err(PoolError::KeyNotFound {
                 key: key.to_string(),
@@ -98,33 +247,53 @@ impl Pool {
                     source: exception,
                 })?;
 
-        let data_container = DataContainer::new_owned(data_container_ffi);
-
-        // Verify type safety
-        let expected_type = T::data_type();
-        let actual_type = data_container.data_type();
+        Ok(DataContainer::new_owned(data_container_ffi))
+    }
 
-        if actual_type != expected_type {
-            return Err(PoolError::TypeMismatch {
+    pub(crate) fn set_any(
+        &mut self,
+        key: &str,
+        data_container: DataContainer<'_, data_type::Any>,
+    ) -> Result<(), PoolError> {
+        let data_type = data_container.data_type();
+        if matches!(data_type, DataType::StereoSample | DataType::MatrixFloat) {
+            return Err(PoolError::UnsupportedType {
                 key: key.to_string(),
-                expected: expected_type,
-                actual: actual_type,
+                data_type,
             });
         }
 
-        Ok(data_container)
+        self.inner
+            .pin_mut()
+            .set(key, data_container.into_owned_ptr())
+            .map_err(|exception| PoolError::Internal {
+                key: key.to_string(),
+                source: exception,
+            })
     }
 
     pub fn contains(&self, key: &str) -> bool {
         self.inner.as_ref().unwrap().contains(key)
     }
 
+    /// Returns whether `key` holds a series accumulated with [`Pool::add`]
+    /// rather than a single value stored with [`Pool::set`].
+    pub fn is_series(&self, key: &str) -> bool {
+        self.inner.as_ref().unwrap().is_series(key)
+    }
+
     pub fn keys(&self) -> Vec<String> {
         self.inner.as_ref().unwrap().keys()
     }
 
+    /// Returns the keys starting with `prefix`, e.g. `lowlevel.` for every
+    /// descriptor in the `lowlevel` namespace.
+    pub fn keys_with_prefix(&self, prefix: &str) -> Vec<String> {
+        self.inner.as_ref().unwrap().keys_with_prefix(prefix)
+    }
+
     pub fn len(&self) -> usize {
-        self.keys().len()
+        self.inner.as_ref().unwrap().size()
     }
 
     pub fn is_empty(&self) -> bool {
@@ -148,6 +317,18 @@ pub enum PoolError {
         actual: DataType,
     },
 
+    #[error("Key '{key}' cannot hold a single {data_type}, add it to a series instead")]
+    UnsupportedType { key: String, data_type: DataType },
+
+    #[error("Key '{key}' holds a series of tensors, which has no data type")]
+    TensorSeries { key: String },
+
+    #[error("Failed to merge pools: {source}")]
+    Merge {
+        #[source]
+        source: cxx::Exception,
+    },
+
     #[error("Internal error for key '{key}': {source}")]
     Internal {
         key: String,
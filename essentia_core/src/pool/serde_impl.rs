@@ -0,0 +1,159 @@
+//! Serde support for pools, written as a map from descriptor name to an
+//! entry holding the value and whether the descriptor is a series, so that
+//! series are restored with [`Pool::add`] and can keep growing.
+
+use std::fmt;
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::{self, SerializeMap, SerializeStruct},
+};
+
+use crate::data::Value;
+
+use super::Pool;
+
+const ENTRY_FIELDS: &[&str] = &["series", "value"];
+
+/// A pool descriptor, written as `{"series": ..., "value": ...}` where the
+/// value of a series holds all of its elements.
+struct Entry {
+    series: bool,
+    value: Value,
+}
+
+impl Serialize for Entry {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Entry", 2)?;
+        state.serialize_field("series", &self.series)?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+
+struct EntryVisitor;
+
+impl<'de> Visitor<'de> for EntryVisitor {
+    type Value = Entry;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a pool descriptor")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let series = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let value = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        Ok(Entry { series, value })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        // `series` may be left out for single values.
+        let mut series = None;
+        let mut value = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "series" if series.is_some() => return Err(de::Error::duplicate_field("series")),
+                "series" => series = Some(map.next_value()?),
+                "value" if value.is_some() => return Err(de::Error::duplicate_field("value")),
+                "value" => value = Some(map.next_value()?),
+                _ => return Err(de::Error::unknown_field(&key, ENTRY_FIELDS)),
+            }
+        }
+
+        Ok(Entry {
+            series: series.unwrap_or(false),
+            value: value.ok_or_else(|| de::Error::missing_field("value"))?,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for Entry {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("Entry", ENTRY_FIELDS, EntryVisitor)
+    }
+}
+
+/// Splits the value read back from a series into the elements that were
+/// added to it.
+fn series_elements(value: Value) -> Option<Vec<Value>> {
+    fn split<T>(values: Vec<T>, element: fn(T) -> Value) -> Option<Vec<Value>> {
+        Some(values.into_iter().map(element).collect())
+    }
+
+    match value {
+        Value::VectorFloat(values) => split(values, Value::Float),
+        Value::VectorString(values) => split(values, Value::String),
+        Value::VectorStereoSample(values) => split(values, Value::StereoSample),
+        Value::VectorVectorFloat(values) => split(values, Value::VectorFloat),
+        Value::VectorVectorString(values) => split(values, Value::VectorString),
+        Value::VectorMatrixFloat(values) => split(values, Value::MatrixFloat),
+        _ => None,
+    }
+}
+
+impl Serialize for Pool {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut keys = self.keys();
+        keys.sort();
+
+        let mut map = serializer.serialize_map(Some(keys.len()))?;
+        for key in keys {
+            let entry = Entry {
+                series: self.is_series(&key),
+                value: self.get_any(&key).map_err(ser::Error::custom)?.to_value(),
+            };
+            map.serialize_entry(&key, &entry)?;
+        }
+        map.end()
+    }
+}
+
+struct PoolVisitor;
+
+impl<'de> Visitor<'de> for PoolVisitor {
+    type Value = Pool;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of pool descriptors")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut pool = Pool::new();
+
+        while let Some((key, entry)) = map.next_entry::<String, Entry>()? {
+            if entry.series {
+                let data_type = entry.value.data_type();
+                let elements = series_elements(entry.value).ok_or_else(|| {
+                    de::Error::custom(format!("'{key}' cannot hold a series of {data_type}"))
+                })?;
+
+                if elements.is_empty() {
+                    return Err(de::Error::custom(format!("'{key}' is an empty series")));
+                }
+
+                for element in elements {
+                    pool.add_any(&key, element.into_data_container())
+                        .map_err(de::Error::custom)?;
+                }
+            } else {
+                pool.set_any(&key, entry.value.into_data_container())
+                    .map_err(de::Error::custom)?;
+            }
+        }
+
+        Ok(pool)
+    }
+}
+
+impl<'de> Deserialize<'de> for Pool {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(PoolVisitor)
+    }
+}
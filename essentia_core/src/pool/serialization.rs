@@ -0,0 +1,163 @@
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use crate::{
+    algorithm::{ComputeError, ConfigurationError, InputError, OutputError, ParameterError},
+    data::{GetFromDataContainer, data_type},
+    essentia::{CreateAlgorithmError, Essentia},
+    pool::Pool,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Yaml,
+    Json,
+}
+
+impl Format {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Format::Yaml => "yaml",
+            Format::Json => "json",
+        }
+    }
+}
+
+/// Reading and writing pools goes through Essentia's `YamlOutput` and
+/// `YamlInput` algorithms, so the files match those written by Essentia's
+/// own extractors.
+impl Pool {
+    pub fn to_yaml(&self) -> Result<String, PoolSerializationError> {
+        self.write_string(Format::Yaml)
+    }
+
+    pub fn to_json(&self) -> Result<String, PoolSerializationError> {
+        self.write_string(Format::Json)
+    }
+
+    pub fn to_yaml_file(&self, path: impl AsRef<Path>) -> Result<(), PoolSerializationError> {
+        self.write_file(path.as_ref(), Format::Yaml)
+    }
+
+    pub fn to_json_file(&self, path: impl AsRef<Path>) -> Result<(), PoolSerializationError> {
+        self.write_file(path.as_ref(), Format::Json)
+    }
+
+    pub fn from_yaml(yaml: &str) -> Result<Self, PoolSerializationError> {
+        Self::read_string(yaml, Format::Yaml)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, PoolSerializationError> {
+        Self::read_string(json, Format::Json)
+    }
+
+    pub fn from_yaml_file(path: impl AsRef<Path>) -> Result<Self, PoolSerializationError> {
+        Self::read_file(path.as_ref(), Format::Yaml)
+    }
+
+    pub fn from_json_file(path: impl AsRef<Path>) -> Result<Self, PoolSerializationError> {
+        Self::read_file(path.as_ref(), Format::Json)
+    }
+
+    fn write_file(&self, path: &Path, format: Format) -> Result<(), PoolSerializationError> {
+        let essentia = Essentia::new();
+
+        let mut algorithm = essentia
+            .create_algorithm("YamlOutput")?
+            .parameter::<data_type::String>("filename", path_to_str(path)?)?
+            .parameter::<data_type::String>("format", format.as_str())?
+            .configure()?;
+
+        algorithm.set_input::<data_type::Pool>("pool", self.clone())?;
+        algorithm.compute()?;
+
+        Ok(())
+    }
+
+    fn read_file(path: &Path, format: Format) -> Result<Self, PoolSerializationError> {
+        let essentia = Essentia::new();
+
+        let mut algorithm = essentia
+            .create_algorithm("YamlInput")?
+            .parameter::<data_type::String>("filename", path_to_str(path)?)?
+            .parameter::<data_type::String>("format", format.as_str())?
+            .configure()?;
+
+        let result = algorithm.compute()?;
+        let pool = result.output::<data_type::Pool>("pool")?.get();
+
+        Ok(pool)
+    }
+
+    fn write_string(&self, format: Format) -> Result<String, PoolSerializationError> {
+        let file = TemporaryFile::new(format)?;
+
+        self.write_file(&file.path, format)?;
+
+        Ok(std::fs::read_to_string(&file.path)?)
+    }
+
+    fn read_string(content: &str, format: Format) -> Result<Self, PoolSerializationError> {
+        let file = TemporaryFile::new(format)?;
+
+        std::fs::write(&file.path, content)?;
+
+        Self::read_file(&file.path, format)
+    }
+}
+
+fn path_to_str(path: &Path) -> Result<&str, PoolSerializationError> {
+    path.to_str()
+        .ok_or_else(|| PoolSerializationError::InvalidPath(path.to_path_buf()))
+}
+
+/// Essentia can only read and write pools through files, so the string
+/// variants go through a file in a private temporary directory, which no
+/// other user can place a file or symlink in. The directory is removed on
+/// drop.
+struct TemporaryFile {
+    path: PathBuf,
+    _directory: tempfile::TempDir,
+}
+
+impl TemporaryFile {
+    fn new(format: Format) -> std::io::Result<Self> {
+        let directory = tempfile::Builder::new()
+            .prefix("essentia-pool-")
+            .tempdir()?;
+        let path = directory.path().join(format!("pool.{}", format.as_str()));
+
+        Ok(Self {
+            path,
+            _directory: directory,
+        })
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum PoolSerializationError {
+    #[error("Failed to create algorithm: {0}")]
+    Create(#[from] CreateAlgorithmError),
+
+    #[error("Failed to set parameter: {0}")]
+    Parameter(#[from] ParameterError),
+
+    #[error("Failed to configure algorithm: {0}")]
+    Configuration(#[from] ConfigurationError),
+
+    #[error("Failed to set input: {0}")]
+    Input(#[from] InputError),
+
+    #[error("Failed to compute: {0}")]
+    Compute(#[from] ComputeError),
+
+    #[error("Failed to get output: {0}")]
+    Output(#[from] OutputError),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Path '{}' is not valid UTF-8", .0.display())]
+    InvalidPath(PathBuf),
+}
@@ -0,0 +1,83 @@
+use std::collections::BTreeMap;
+
+use super::Pool;
+
+/// The keys of a [`Pool`] arranged by their dot-separated namespaces, so
+/// `lowlevel.spectral_centroid` is the descriptor `spectral_centroid` in the
+/// namespace `lowlevel`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyTree {
+    descriptors: BTreeMap<String, String>,
+    namespaces: BTreeMap<String, KeyTree>,
+}
+
+impl KeyTree {
+    pub fn from_keys<I, K>(keys: I) -> Self
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        let mut tree = KeyTree::default();
+
+        for key in keys {
+            let key = key.as_ref();
+            let mut node = &mut tree;
+            let mut segments = key.split('.').peekable();
+
+            while let Some(segment) = segments.next() {
+                if segments.peek().is_some() {
+                    node = node.namespaces.entry(segment.to_string()).or_default();
+                } else {
+                    node.descriptors
+                        .insert(segment.to_string(), key.to_string());
+                }
+            }
+        }
+
+        tree
+    }
+
+    /// Returns the subtree of a namespace, which may itself be nested, e.g.
+    /// `lowlevel` or `lowlevel.mfcc`.
+    pub fn namespace(&self, namespace: &str) -> Option<&KeyTree> {
+        namespace
+            .split('.')
+            .try_fold(self, |node, segment| node.namespaces.get(segment))
+    }
+
+    /// Returns the child namespaces and their subtrees in sorted order.
+    pub fn namespaces(&self) -> impl Iterator<Item = (&str, &KeyTree)> {
+        self.namespaces
+            .iter()
+            .map(|(name, tree)| (name.as_str(), tree))
+    }
+
+    /// Returns the descriptors directly in this namespace as pairs of their
+    /// name and full key, in sorted order.
+    pub fn descriptors(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.descriptors
+            .iter()
+            .map(|(name, key)| (name.as_str(), key.as_str()))
+    }
+
+    /// Returns the full keys of all descriptors in this namespace and below.
+    pub fn keys(&self) -> Vec<&str> {
+        let mut keys: Vec<&str> = self.descriptors.values().map(String::as_str).collect();
+
+        for tree in self.namespaces.values() {
+            keys.extend(tree.keys());
+        }
+
+        keys
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.descriptors.is_empty() && self.namespaces.is_empty()
+    }
+}
+
+impl Pool {
+    pub fn key_tree(&self) -> KeyTree {
+        KeyTree::from_keys(self.keys())
+    }
+}
@@ -0,0 +1,90 @@
+use cxx::UniquePtr;
+use essentia_sys::ffi;
+use std::marker::PhantomData;
+
+use crate::{
+    IntoDataContainer,
+    algorithm::{ConfigurationError, Configured, Initialized, Introspection, ParameterError},
+    data::{ParameterData, types::HasDataType},
+    essentia::Essentia,
+};
+
+pub struct StreamingAlgorithm<'a, State = Initialized> {
+    pub(crate) algorithm_bridge: UniquePtr<ffi::StreamingAlgorithmBridge>,
+    state: State,
+    introspection: Introspection,
+    _marker: PhantomData<&'a Essentia>,
+}
+
+impl<'a, State> StreamingAlgorithm<'a, State> {
+    pub fn introspection(&self) -> &Introspection {
+        &self.introspection
+    }
+}
+
+impl<'a> StreamingAlgorithm<'a, Initialized> {
+    pub(crate) fn new(
+        algorithm_bridge: UniquePtr<ffi::StreamingAlgorithmBridge>,
+    ) -> Result<Self, cxx::Exception> {
+        let introspection = Introspection::from_streaming_algorithm_bridge(&algorithm_bridge)?;
+
+        Ok(Self {
+            algorithm_bridge,
+            state: Initialized::new(),
+            introspection,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn parameter<T>(
+        mut self,
+        key: &str,
+        value: impl IntoDataContainer<T>,
+    ) -> Result<Self, ParameterError>
+    where
+        T: ParameterData + HasDataType,
+    {
+        self.set_parameter(key, value)?;
+        Ok(self)
+    }
+
+    pub fn set_parameter<T>(
+        &mut self,
+        key: &str,
+        value: impl IntoDataContainer<T>,
+    ) -> Result<(), ParameterError>
+    where
+        T: ParameterData + HasDataType,
+    {
+        let data_container = value.into_data_container();
+
+        crate::algorithm::validate_parameter(&self.introspection, key, &data_container)?;
+
+        self.state
+            .parameters
+            .insert(key.to_string(), data_container.into_any());
+
+        Ok(())
+    }
+
+    pub fn configure(mut self) -> Result<StreamingAlgorithm<'a, Configured>, ConfigurationError> {
+        let parameter_map = self.state.into_parameter_map();
+
+        self.algorithm_bridge
+            .pin_mut()
+            .configure(parameter_map.parameter_map_bridge)?;
+
+        Ok(StreamingAlgorithm {
+            algorithm_bridge: self.algorithm_bridge,
+            state: Configured,
+            introspection: self.introspection,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'a> StreamingAlgorithm<'a, Configured> {
+    pub(crate) fn into_parts(self) -> (UniquePtr<ffi::StreamingAlgorithmBridge>, Introspection) {
+        (self.algorithm_bridge, self.introspection)
+    }
+}
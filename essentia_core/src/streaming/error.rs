@@ -0,0 +1,40 @@
+use thiserror::Error;
+
+use crate::data::DataType;
+
+#[derive(Debug, Error)]
+pub enum ConnectionError {
+    #[error("Source '{source_name}' not found on algorithm '{algorithm}'")]
+    SourceNotFound {
+        algorithm: String,
+        source_name: String,
+    },
+
+    #[error("Sink '{sink_name}' not found on algorithm '{algorithm}'")]
+    SinkNotFound {
+        algorithm: String,
+        sink_name: String,
+    },
+
+    #[error("Type mismatch for connector '{connector}': expected {expected}, found {actual}")]
+    TypeMismatch {
+        connector: String,
+        expected: DataType,
+        actual: DataType,
+    },
+
+    #[error("Connection failed: {0}")]
+    Internal(#[from] cxx::Exception),
+}
+
+#[derive(Debug, Error)]
+pub enum NetworkError {
+    #[error("Network run failed: {0}")]
+    Run(#[source] cxx::Exception),
+
+    #[error("Network reset failed: {0}")]
+    Reset(#[source] cxx::Exception),
+
+    #[error("Vector output not available: {0}")]
+    Output(#[source] cxx::Exception),
+}
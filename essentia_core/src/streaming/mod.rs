@@ -0,0 +1,7 @@
+mod algorithm;
+mod error;
+mod network;
+
+pub use algorithm::StreamingAlgorithm;
+pub use error::*;
+pub use network::{Network, Node, Sink, Source, VectorOutput};
@@ -0,0 +1,257 @@
+use cxx::UniquePtr;
+use essentia_sys::ffi;
+use std::marker::PhantomData;
+
+use crate::{
+    IntoDataContainer,
+    algorithm::{Configured, Introspection},
+    data::{DataContainer, PoolData, StreamData, types::HasDataType},
+    essentia::Essentia,
+    pool::Pool,
+    streaming::{ConnectionError, NetworkError, StreamingAlgorithm},
+};
+
+pub struct Source<T> {
+    node: usize,
+    name: String,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Source<T> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+pub struct Sink<T> {
+    node: usize,
+    name: String,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Sink<T> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+pub struct Node {
+    index: usize,
+    introspection: Introspection,
+}
+
+impl Node {
+    pub fn introspection(&self) -> &Introspection {
+        &self.introspection
+    }
+
+    pub fn source<T>(&self, name: &str) -> Result<Source<T>, ConnectionError>
+    where
+        T: HasDataType,
+    {
+        let source_info =
+            self.introspection
+                .get_output(name)
+                .ok_or_else(|| ConnectionError::SourceNotFound {
+                    algorithm: self.introspection.name().to_string(),
+                    source_name: name.to_string(),
+                })?;
+
+        let expected_type = T::data_type();
+        let source_data_type = source_info.input_output_type();
+
+        if source_data_type != expected_type {
+            return Err(ConnectionError::TypeMismatch {
+                connector: name.to_string(),
+                expected: expected_type,
+                actual: source_data_type,
+            });
+        }
+
+        Ok(Source {
+            node: self.index,
+            name: name.to_string(),
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn sink<T>(&self, name: &str) -> Result<Sink<T>, ConnectionError>
+    where
+        T: HasDataType,
+    {
+        let sink_info =
+            self.introspection
+                .get_input(name)
+                .ok_or_else(|| ConnectionError::SinkNotFound {
+                    algorithm: self.introspection.name().to_string(),
+                    sink_name: name.to_string(),
+                })?;
+
+        let expected_type = T::data_type();
+        let sink_data_type = sink_info.input_output_type();
+
+        if sink_data_type != expected_type {
+            return Err(ConnectionError::TypeMismatch {
+                connector: name.to_string(),
+                expected: expected_type,
+                actual: sink_data_type,
+            });
+        }
+
+        Ok(Sink {
+            node: self.index,
+            name: name.to_string(),
+            _marker: PhantomData,
+        })
+    }
+}
+
+pub struct VectorOutput<T> {
+    index: usize,
+    _marker: PhantomData<T>,
+}
+
+/// A graph of streaming algorithms driven by Essentia's scheduler.
+///
+/// The network owns every algorithm added to it. The generator driving the
+/// graph when calling [`Network::run`] is the first node added without
+/// inputs, e.g. a vector input or an audio loader, unless one is chosen with
+/// [`Network::set_generator`].
+pub struct Network<'a> {
+    network_bridge: UniquePtr<ffi::NetworkBridge>,
+    _marker: PhantomData<&'a Essentia>,
+}
+
+impl<'a> Network<'a> {
+    pub fn new(_essentia: &'a Essentia) -> Self {
+        Self {
+            network_bridge: ffi::create_network_bridge(),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn add(&mut self, algorithm: StreamingAlgorithm<'a, Configured>) -> Node {
+        let (algorithm_bridge, introspection) = algorithm.into_parts();
+
+        let index = self
+            .network_bridge
+            .pin_mut()
+            .add_algorithm(algorithm_bridge);
+
+        Node {
+            index,
+            introspection,
+        }
+    }
+
+    pub fn add_vector_input<T>(
+        &mut self,
+        data: impl IntoDataContainer<T::Sequence>,
+    ) -> Result<Source<T>, ConnectionError>
+    where
+        T: StreamData,
+    {
+        let owned_ptr = data.into_data_container().into_owned_ptr();
+
+        let node = self.network_bridge.pin_mut().add_vector_input(owned_ptr)?;
+
+        Ok(Source {
+            node,
+            name: "data".to_string(),
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn set_generator(&mut self, node: &Node) -> Result<(), ConnectionError> {
+        self.network_bridge.pin_mut().set_generator(node.index)?;
+
+        Ok(())
+    }
+
+    pub fn add_vector_output<T>(
+        &mut self,
+        source: &Source<T>,
+    ) -> Result<VectorOutput<T>, ConnectionError>
+    where
+        T: StreamData,
+    {
+        let index = self.network_bridge.pin_mut().add_vector_output(
+            source.node,
+            &source.name,
+            T::data_type().into(),
+        )?;
+
+        Ok(VectorOutput {
+            index,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn connect<T>(
+        &mut self,
+        source: &Source<T>,
+        sink: &Sink<T>,
+    ) -> Result<(), ConnectionError> {
+        self.network_bridge
+            .pin_mut()
+            .connect(source.node, &source.name, sink.node, &sink.name)?;
+
+        Ok(())
+    }
+
+    pub fn connect_to_pool<T>(
+        &mut self,
+        source: &Source<T>,
+        descriptor_name: &str,
+    ) -> Result<(), ConnectionError>
+    where
+        T: PoolData,
+    {
+        self.network_bridge.pin_mut().connect_to_pool(
+            source.node,
+            &source.name,
+            descriptor_name,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn discard<T>(&mut self, source: &Source<T>) -> Result<(), ConnectionError> {
+        self.network_bridge
+            .pin_mut()
+            .connect_to_nowhere(source.node, &source.name)?;
+
+        Ok(())
+    }
+
+    pub fn run(&mut self) -> Result<(), NetworkError> {
+        self.network_bridge
+            .pin_mut()
+            .run()
+            .map_err(NetworkError::Run)
+    }
+
+    pub fn reset(&mut self) -> Result<(), NetworkError> {
+        self.network_bridge
+            .pin_mut()
+            .reset()
+            .map_err(NetworkError::Reset)
+    }
+
+    pub fn vector_output<T>(
+        &self,
+        output: &VectorOutput<T>,
+    ) -> Result<DataContainer<'_, T::Sequence>, NetworkError>
+    where
+        T: StreamData,
+    {
+        self.network_bridge
+            .get_vector_output(output.index)
+            .map(DataContainer::new_borrowed)
+            .map_err(NetworkError::Output)
+    }
+
+    pub fn pool(&self) -> Pool {
+        Pool::new_from_bridge(self.network_bridge.get_pool().clone())
+    }
+}
@@ -0,0 +1,48 @@
+use essentia_core::{Essentia, Value, data_type};
+
+#[test]
+fn reconfigure_overwrites_an_existing_parameter() {
+    let essentia = Essentia::new();
+
+    let algorithm = essentia
+        .create_algorithm("Windowing")
+        .unwrap()
+        .parameter::<data_type::Int>("size", 1024)
+        .unwrap()
+        .parameter::<data_type::String>("type", "hann")
+        .unwrap()
+        .configure()
+        .unwrap();
+
+    let algorithm = algorithm
+        .reconfigure()
+        .unwrap()
+        .parameter::<data_type::Int>("size", 512)
+        .unwrap()
+        .configure()
+        .unwrap();
+
+    let parameters = algorithm.parameters().unwrap();
+    assert!(matches!(parameters["size"], Value::Int(512)));
+    assert!(matches!(&parameters["type"], Value::String(window) if window == "hann"));
+}
+
+#[test]
+fn setting_a_parameter_twice_keeps_the_last_value() {
+    let essentia = Essentia::new();
+
+    let algorithm = essentia
+        .create_algorithm("Windowing")
+        .unwrap()
+        .parameter::<data_type::Int>("size", 1024)
+        .unwrap()
+        .parameter::<data_type::Int>("size", 256)
+        .unwrap()
+        .configure()
+        .unwrap();
+
+    assert!(matches!(
+        algorithm.parameters().unwrap()["size"],
+        Value::Int(256)
+    ));
+}
@@ -0,0 +1,182 @@
+use essentia_core::{DataType, Pool, PoolError, data::StereoSample, data_type};
+use ndarray::{Array2, Array4};
+
+fn stereo(left: f32, right: f32) -> StereoSample {
+    StereoSample { left, right }
+}
+
+fn assert_stereo_eq(actual: &[StereoSample], expected: &[StereoSample]) {
+    assert_eq!(actual.len(), expected.len());
+    for (actual, expected) in actual.iter().zip(expected) {
+        assert_eq!((actual.left, actual.right), (expected.left, expected.right));
+    }
+}
+
+#[test]
+fn set_and_get_round_trip_single_values() {
+    let mut pool = Pool::new();
+
+    pool.set::<data_type::Float>("float", 0.5).unwrap();
+    assert_eq!(pool.get::<data_type::Float, f32>("float").unwrap(), 0.5);
+
+    pool.set::<data_type::String>("string", "key").unwrap();
+    assert_eq!(
+        pool.get::<data_type::String, String>("string").unwrap(),
+        "key"
+    );
+
+    pool.set::<data_type::VectorFloat>("vector_float", [1.0, 2.0].as_slice())
+        .unwrap();
+    assert_eq!(
+        pool.get::<data_type::VectorFloat, Vec<f32>>("vector_float")
+            .unwrap(),
+        vec![1.0, 2.0]
+    );
+
+    pool.set::<data_type::VectorString>("vector_string", ["a", "b"].as_slice())
+        .unwrap();
+    assert_eq!(
+        pool.get::<data_type::VectorString, Vec<String>>("vector_string")
+            .unwrap(),
+        vec!["a", "b"]
+    );
+
+    let tensor = Array4::from_shape_fn((1, 2, 2, 3), |(_, i, j, k)| (i * 6 + j * 3 + k) as f32);
+    pool.set::<data_type::TensorFloat>("tensor", &tensor)
+        .unwrap();
+    assert_eq!(
+        pool.get::<data_type::TensorFloat, Array4<f32>>("tensor")
+            .unwrap(),
+        tensor
+    );
+}
+
+#[test]
+fn set_and_get_round_trip_series() {
+    let mut pool = Pool::new();
+
+    let samples = [stereo(0.1, 0.2), stereo(0.3, 0.4)];
+    pool.set::<data_type::VectorStereoSample>("stereo", samples.as_slice())
+        .unwrap();
+    assert_stereo_eq(
+        &pool
+            .get::<data_type::VectorStereoSample, Vec<StereoSample>>("stereo")
+            .unwrap(),
+        &samples,
+    );
+
+    let frames = [vec![1.0, 2.0], vec![3.0]];
+    pool.set::<data_type::VectorVectorFloat>("frames", frames.as_slice())
+        .unwrap();
+    assert_eq!(
+        pool.get::<data_type::VectorVectorFloat, Vec<Vec<f32>>>("frames")
+            .unwrap(),
+        frames
+    );
+
+    let words: [&[&str]; 2] = [&["a", "b"], &["c"]];
+    pool.set::<data_type::VectorVectorString>("words", words.as_slice())
+        .unwrap();
+    assert_eq!(
+        pool.get::<data_type::VectorVectorString, Vec<Vec<String>>>("words")
+            .unwrap(),
+        vec![vec!["a", "b"], vec!["c"]]
+    );
+
+    let matrices = [
+        Array2::from_elem((2, 2), 1.0),
+        Array2::from_elem((1, 3), 2.0),
+    ];
+    pool.set::<data_type::VectorMatrixFloat>("matrices", matrices.as_slice())
+        .unwrap();
+    assert_eq!(
+        pool.get::<data_type::VectorMatrixFloat, Vec<Array2<f32>>>("matrices")
+            .unwrap(),
+        matrices
+    );
+}
+
+#[test]
+fn set_rejects_a_single_stereo_sample() {
+    let mut pool = Pool::new();
+
+    let error = pool
+        .set::<data_type::StereoSample>("stereo", stereo(0.1, 0.2))
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        PoolError::UnsupportedType {
+            data_type: DataType::StereoSample,
+            ..
+        }
+    ));
+    assert!(!pool.contains("stereo"));
+}
+
+#[test]
+fn set_rejects_a_single_matrix() {
+    let mut pool = Pool::new();
+
+    let error = pool
+        .set::<data_type::MatrixFloat>("matrix", &Array2::from_elem((2, 2), 1.0))
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        PoolError::UnsupportedType {
+            data_type: DataType::MatrixFloat,
+            ..
+        }
+    ));
+    assert!(!pool.contains("matrix"));
+}
+
+#[test]
+fn add_accumulates_stereo_samples_and_matrices() {
+    let mut pool = Pool::new();
+
+    pool.add::<data_type::StereoSample>("stereo", stereo(0.1, 0.2))
+        .unwrap();
+    pool.add::<data_type::StereoSample>("stereo", stereo(0.3, 0.4))
+        .unwrap();
+    assert_stereo_eq(
+        &pool
+            .get_series::<data_type::StereoSample, Vec<StereoSample>>("stereo")
+            .unwrap(),
+        &[stereo(0.1, 0.2), stereo(0.3, 0.4)],
+    );
+
+    let matrix = Array2::from_elem((2, 2), 1.0);
+    pool.add::<data_type::MatrixFloat>("matrix", &matrix)
+        .unwrap();
+    assert_eq!(
+        pool.get_series::<data_type::MatrixFloat, Vec<Array2<f32>>>("matrix")
+            .unwrap(),
+        vec![matrix]
+    );
+}
+
+#[test]
+fn iter_yields_every_descriptor() {
+    let mut pool = Pool::new();
+
+    pool.set::<data_type::Float>("float", 0.5).unwrap();
+    pool.add::<data_type::VectorFloat>("frames", [1.0, 2.0].as_slice())
+        .unwrap();
+
+    let mut descriptors = pool
+        .iter()
+        .map(|descriptor| descriptor.map(|(key, data_type, _)| (key, data_type)))
+        .collect::<Result<Vec<_>, PoolError>>()
+        .unwrap();
+    descriptors.sort_by(|a, b| a.0.cmp(&b.0));
+
+    assert_eq!(
+        descriptors,
+        vec![
+            ("float".to_string(), DataType::Float),
+            ("frames".to_string(), DataType::VectorVectorFloat),
+        ]
+    );
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "serde")]
+
+use essentia_core::{Pool, Value, data_type};
+use serde_json::json;
+
+#[test]
+fn value_is_tagged_with_its_data_type() {
+    let json = serde_json::to_value(Value::Long(1 << 40)).unwrap();
+    assert_eq!(json, json!({ "Long": 1_i64 << 40 }));
+
+    let value: Value = serde_json::from_value(json).unwrap();
+    assert!(matches!(value, Value::Long(value) if value == 1 << 40));
+}
+
+#[test]
+fn pool_round_trips_through_json_value() {
+    let mut pool = Pool::new();
+    pool.set::<data_type::String>("metadata.title", "song")
+        .unwrap();
+    pool.set::<data_type::VectorFloat>("rhythm.beats", [0.5, 1.0].as_slice())
+        .unwrap();
+    pool.add::<data_type::Float>("lowlevel.loudness", 0.25)
+        .unwrap();
+    pool.add::<data_type::Float>("lowlevel.loudness", 0.75)
+        .unwrap();
+
+    let mut restored: Pool = serde_json::from_value(serde_json::to_value(&pool).unwrap()).unwrap();
+
+    assert_eq!(
+        restored
+            .get::<data_type::String, String>("metadata.title")
+            .unwrap(),
+        "song"
+    );
+    assert_eq!(
+        restored
+            .get::<data_type::VectorFloat, Vec<f32>>("rhythm.beats")
+            .unwrap(),
+        vec![0.5, 1.0]
+    );
+    assert!(!restored.is_series("rhythm.beats"));
+
+    restored
+        .add::<data_type::Float>("lowlevel.loudness", 1.0)
+        .unwrap();
+    assert_eq!(
+        restored
+            .get_series::<data_type::Float, Vec<f32>>("lowlevel.loudness")
+            .unwrap(),
+        vec![0.25, 0.75, 1.0]
+    );
+}
+
+#[test]
+fn pool_entries_do_not_depend_on_key_order() {
+    let pool: Pool = serde_json::from_str(
+        r#"{"beats": {"value": {"VectorFloat": [0.5]}, "series": true}, "bpm": {"value": {"Float": 120.0}}}"#,
+    )
+    .unwrap();
+
+    assert!(pool.is_series("beats"));
+    assert_eq!(
+        pool.get_series::<data_type::Float, Vec<f32>>("beats")
+            .unwrap(),
+        vec![0.5]
+    );
+    assert_eq!(pool.get::<data_type::Float, f32>("bpm").unwrap(), 120.0);
+}
@@ -17,6 +17,11 @@ struct InputOutputInfo;
 DataType
 essentia_param_type_to_enum(essentia::Parameter::ParamType essentia_type);
 DataType type_info_to_io_enum(const std::type_info *type_info);
+rust::Vec<ParameterInfo>
+configurable_parameter_infos(const essentia::Configurable &configurable);
+std::unique_ptr<DataContainer>
+configurable_default_parameter(const essentia::Configurable &configurable,
+                               rust::Str parameter_name);
 
 class AlgorithmBridge {
 private:
@@ -39,10 +44,15 @@ public:
   void configure(std::unique_ptr<ParameterMapBridge> parameter_map_bridge);
   void compute();
 
+  rust::Vec<rust::String> get_parameter_names() const;
+  std::unique_ptr<DataContainer> get_parameter(rust::Str parameter_name) const;
+
   rust::String get_name() const;
   rust::String get_category() const;
   rust::String get_description() const;
   rust::Vec<ParameterInfo> get_parameter_infos() const;
+  std::unique_ptr<DataContainer>
+  get_default_parameter(rust::Str parameter_name) const;
   rust::Vec<InputOutputInfo> get_input_infos() const;
   rust::Vec<InputOutputInfo> get_output_infos() const;
 
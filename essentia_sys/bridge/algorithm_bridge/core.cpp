@@ -1,4 +1,5 @@
 #include "algorithm_bridge.h"
+#include <stdexcept>
 
 namespace essentia_bridge {
 
@@ -14,6 +15,29 @@ void AlgorithmBridge::configure(
 
 void AlgorithmBridge::compute() { _algorithm->compute(); }
 
+rust::Vec<rust::String> AlgorithmBridge::get_parameter_names() const {
+  rust::Vec<rust::String> parameter_names;
+
+  for (const auto &param : _algorithm->parameters()) {
+    parameter_names.push_back(rust::String(param.first));
+  }
+
+  return parameter_names;
+}
+
+std::unique_ptr<DataContainer>
+AlgorithmBridge::get_parameter(rust::Str parameter_name) const {
+  const auto &params = _algorithm->parameters();
+
+  auto param_it = params.find(std::string(parameter_name));
+  if (param_it == params.end()) {
+    throw std::runtime_error(std::string("Parameter not found: ") +
+                             std::string(parameter_name));
+  }
+
+  return parameter_to_data_container(param_it->second);
+}
+
 void AlgorithmBridge::reset() { _algorithm->reset(); }
 
 } // namespace essentia_bridge
\ No newline at end of file
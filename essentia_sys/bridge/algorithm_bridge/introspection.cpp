@@ -76,12 +76,13 @@ rust::String AlgorithmBridge::get_description() const {
   }
 }
 
-rust::Vec<ParameterInfo> AlgorithmBridge::get_parameter_infos() const {
+rust::Vec<ParameterInfo>
+configurable_parameter_infos(const essentia::Configurable &configurable) {
   rust::Vec<ParameterInfo> param_infos;
 
-  const auto &param_descriptions = _algorithm->parameterDescription;
-  const auto &param_ranges = _algorithm->parameterRange;
-  const auto &default_params = _algorithm->defaultParameters();
+  const auto &param_descriptions = configurable.parameterDescription;
+  const auto &param_ranges = configurable.parameterRange;
+  const auto &default_params = configurable.defaultParameters();
 
   for (const auto &param_desc : param_descriptions) {
     const std::string &param_name = param_desc.first;
@@ -114,6 +115,29 @@ rust::Vec<ParameterInfo> AlgorithmBridge::get_parameter_infos() const {
   return param_infos;
 }
 
+std::unique_ptr<DataContainer>
+configurable_default_parameter(const essentia::Configurable &configurable,
+                               rust::Str parameter_name) {
+  const auto &default_params = configurable.defaultParameters();
+
+  auto param_it = default_params.find(std::string(parameter_name));
+  if (param_it == default_params.end()) {
+    throw std::runtime_error(std::string("Parameter not found: ") +
+                             std::string(parameter_name));
+  }
+
+  return parameter_to_data_container(param_it->second);
+}
+
+rust::Vec<ParameterInfo> AlgorithmBridge::get_parameter_infos() const {
+  return configurable_parameter_infos(*_algorithm);
+}
+
+std::unique_ptr<DataContainer>
+AlgorithmBridge::get_default_parameter(rust::Str parameter_name) const {
+  return configurable_default_parameter(*_algorithm, parameter_name);
+}
+
 rust::Vec<InputOutputInfo> AlgorithmBridge::get_input_infos() const {
   rust::Vec<InputOutputInfo> input_infos;
 
@@ -31,4 +31,27 @@ namespace essentia_bridge
     return std::make_unique<AlgorithmBridge>(algorithm);
   }
 
+  rust::Vec<rust::String> get_streaming_algorithm_names()
+  {
+    std::vector<std::string> algorithm_names =
+        essentia::streaming::AlgorithmFactory::keys();
+    rust::Vec<rust::String> result;
+    result.reserve(algorithm_names.size());
+
+    for (const auto &algorithm_name : algorithm_names)
+    {
+      result.push_back(rust::String(algorithm_name));
+    }
+
+    return result;
+  }
+
+  std::unique_ptr<StreamingAlgorithmBridge>
+  create_streaming_algorithm_bridge(rust::Str algorithm_name)
+  {
+    essentia::streaming::Algorithm *algorithm =
+        essentia::streaming::AlgorithmFactory::create(std::string(algorithm_name));
+    return std::make_unique<StreamingAlgorithmBridge>(algorithm);
+  }
+
 } // namespace essentia_bridge
@@ -2,8 +2,10 @@
 
 #include "algorithm_bridge/algorithm_bridge.h"
 #include "data_container/data_container.h"
+#include "network_bridge/network_bridge.h"
 #include "parameter_map_bridge/parameter_map_bridge.h"
 #include "pool_bridge/pool_bridge.h"
+#include "streaming_algorithm_bridge/streaming_algorithm_bridge.h"
 #include "rust/cxx.h"
 #include <essentia/algorithm.h>
 #include <essentia/utils/tnt/tnt_array2d.h>
@@ -19,6 +21,9 @@ void shutdown_essentia();
 rust::Vec<rust::String> get_algorithm_names();
 std::unique_ptr<AlgorithmBridge>
 create_algorithm_bridge(rust::Str algorithm_name);
+rust::Vec<rust::String> get_streaming_algorithm_names();
+std::unique_ptr<StreamingAlgorithmBridge>
+create_streaming_algorithm_bridge(rust::Str algorithm_name);
 std::unique_ptr<ParameterMapBridge> create_parameter_map_bridge();
 std::unique_ptr<PoolBridge> create_pool_bridge();
 
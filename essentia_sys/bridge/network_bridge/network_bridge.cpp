@@ -0,0 +1,205 @@
+#include "network_bridge.h"
+#include "essentia-sys/src/lib.rs.h"
+#include <essentia/streaming/algorithms/devnull.h>
+#include <essentia/streaming/algorithms/poolstorage.h>
+#include <stdexcept>
+
+namespace essentia_bridge {
+
+NetworkBridge::NetworkBridge() : _pool_bridge(std::make_unique<PoolBridge>()) {}
+
+NetworkBridge::~NetworkBridge() {
+  // The scheduler network does not own the algorithms, so it has to go first.
+  _network.reset();
+  for (auto *algorithm : _algorithms) {
+    delete algorithm;
+  }
+}
+
+essentia::streaming::SourceBase &NetworkBridge::source(std::size_t node,
+                                                       rust::Str source_name) {
+  return _algorithms.at(node)->output(std::string(source_name));
+}
+
+essentia::streaming::SinkBase &NetworkBridge::sink(std::size_t node,
+                                                   rust::Str sink_name) {
+  return _algorithms.at(node)->input(std::string(sink_name));
+}
+
+std::size_t NetworkBridge::add_node(essentia::streaming::Algorithm *algorithm) {
+  _network.reset();
+  _algorithms.push_back(algorithm);
+  return _algorithms.size() - 1;
+}
+
+std::size_t NetworkBridge::add_generator_candidate(
+    essentia::streaming::Algorithm *algorithm) {
+  auto node = add_node(algorithm);
+  if (!_generator && algorithm->inputs().empty()) {
+    _generator = node;
+  }
+  return node;
+}
+
+void NetworkBridge::adopt_last_sink_parent(
+    essentia::streaming::SourceBase &source) {
+  // The Essentia connect helpers for pools and NOWHERE allocate their storage
+  // algorithm internally; keep track of it so it is deleted with the network.
+  add_node(source.sinks().back()->parent());
+}
+
+std::size_t NetworkBridge::add_algorithm(
+    std::unique_ptr<StreamingAlgorithmBridge> algorithm_bridge) {
+  return add_generator_candidate(algorithm_bridge->release());
+}
+
+std::size_t NetworkBridge::add_vector_input(
+    std::unique_ptr<DataContainer> data_container) {
+  switch (data_container->get_data_type()) {
+  case DataType::VectorFloat:
+    return generic_add_vector_input<float>(std::move(data_container));
+
+  case DataType::VectorInt:
+    return generic_add_vector_input<int>(std::move(data_container));
+
+  case DataType::VectorString:
+    return generic_add_vector_input<std::string>(std::move(data_container));
+
+  case DataType::VectorStereoSample:
+    return generic_add_vector_input<essentia::StereoSample>(
+        std::move(data_container));
+
+  case DataType::VectorComplex:
+    return generic_add_vector_input<std::complex<essentia::Real>>(
+        std::move(data_container));
+
+  case DataType::VectorVectorFloat:
+    return generic_add_vector_input<std::vector<float>>(
+        std::move(data_container));
+
+  case DataType::VectorVectorString:
+    return generic_add_vector_input<std::vector<std::string>>(
+        std::move(data_container));
+
+  case DataType::VectorVectorStereoSample:
+    return generic_add_vector_input<std::vector<essentia::StereoSample>>(
+        std::move(data_container));
+
+  case DataType::VectorVectorComplex:
+    return generic_add_vector_input<std::vector<std::complex<essentia::Real>>>(
+        std::move(data_container));
+
+  case DataType::VectorMatrixFloat:
+    return generic_add_vector_input<TNT::Array2D<float>>(
+        std::move(data_container));
+
+  default:
+    throw std::invalid_argument{"NetworkBridge::add_vector_input: "
+                                "unsupported DataType value"};
+  }
+}
+
+std::size_t NetworkBridge::add_vector_output(std::size_t node,
+                                             rust::Str source_name,
+                                             DataType data_type) {
+  auto &src = source(node, source_name);
+
+  switch (data_type) {
+  case DataType::Float:
+    return generic_add_vector_output<float>(src);
+
+  case DataType::Int:
+    return generic_add_vector_output<int>(src);
+
+  case DataType::String:
+    return generic_add_vector_output<std::string>(src);
+
+  case DataType::StereoSample:
+    return generic_add_vector_output<essentia::StereoSample>(src);
+
+  case DataType::Complex:
+    return generic_add_vector_output<std::complex<essentia::Real>>(src);
+
+  case DataType::VectorFloat:
+    return generic_add_vector_output<std::vector<float>>(src);
+
+  case DataType::VectorString:
+    return generic_add_vector_output<std::vector<std::string>>(src);
+
+  case DataType::VectorStereoSample:
+    return generic_add_vector_output<std::vector<essentia::StereoSample>>(src);
+
+  case DataType::VectorComplex:
+    return generic_add_vector_output<
+        std::vector<std::complex<essentia::Real>>>(src);
+
+  case DataType::MatrixFloat:
+    return generic_add_vector_output<TNT::Array2D<float>>(src);
+
+  default:
+    throw std::invalid_argument{"NetworkBridge::add_vector_output: "
+                                "unsupported DataType value"};
+  }
+}
+
+void NetworkBridge::set_generator(std::size_t node) {
+  if (node >= _algorithms.size()) {
+    throw std::out_of_range("NetworkBridge::set_generator: unknown node");
+  }
+  _network.reset();
+  _generator = node;
+}
+
+void NetworkBridge::connect(std::size_t source_node, rust::Str source_name,
+                            std::size_t sink_node, rust::Str sink_name) {
+  _network.reset();
+  essentia::streaming::connect(source(source_node, source_name),
+                               sink(sink_node, sink_name));
+}
+
+void NetworkBridge::connect_to_pool(std::size_t source_node,
+                                    rust::Str source_name,
+                                    rust::Str descriptor_name) {
+  auto &src = source(source_node, source_name);
+  essentia::streaming::connect(src, _pool_bridge->pool(),
+                               std::string(descriptor_name));
+  adopt_last_sink_parent(src);
+}
+
+void NetworkBridge::connect_to_nowhere(std::size_t source_node,
+                                       rust::Str source_name) {
+  auto &src = source(source_node, source_name);
+  essentia::streaming::connect(src, essentia::streaming::NOWHERE);
+  adopt_last_sink_parent(src);
+}
+
+void NetworkBridge::run() {
+  if (!_generator) {
+    throw std::runtime_error("Cannot run a network without a generator");
+  }
+
+  if (!_network) {
+    _network = std::make_unique<essentia::scheduler::Network>(
+        _algorithms.at(*_generator), false);
+  }
+
+  _network->run();
+}
+
+void NetworkBridge::reset() {
+  if (_network) {
+    _network->reset();
+  }
+}
+
+const DataContainer &NetworkBridge::get_vector_output(std::size_t index) const {
+  return *_vector_outputs.at(index);
+}
+
+const PoolBridge &NetworkBridge::get_pool() const { return *_pool_bridge; }
+
+std::unique_ptr<NetworkBridge> create_network_bridge() {
+  return std::make_unique<NetworkBridge>();
+}
+
+} // namespace essentia_bridge
@@ -0,0 +1,87 @@
+#pragma once
+
+#include "../data_container/data_container.h"
+#include "../pool_bridge/pool_bridge.h"
+#include "../streaming_algorithm_bridge/streaming_algorithm_bridge.h"
+#include <essentia/scheduler/network.h>
+#include <essentia/streaming/algorithms/vectorinput.h>
+#include <essentia/streaming/algorithms/vectoroutput.h>
+#include <memory>
+#include <optional>
+#include <rust/cxx.h>
+#include <vector>
+
+namespace essentia_bridge {
+
+enum class DataType : ::std::uint8_t;
+
+class NetworkBridge {
+private:
+  // Every algorithm in the graph, including the vector inputs/outputs and
+  // storage sinks created by the bridge.
+  std::vector<essentia::streaming::Algorithm *> _algorithms;
+
+  // The node driving the graph: set explicitly, or else the first node added
+  // that has no inputs.
+  std::optional<std::size_t> _generator;
+
+  std::vector<std::unique_ptr<DataContainer>> _vector_inputs;
+  std::vector<std::unique_ptr<DataContainer>> _vector_outputs;
+
+  std::unique_ptr<PoolBridge> _pool_bridge;
+  std::unique_ptr<essentia::scheduler::Network> _network;
+
+  essentia::streaming::SourceBase &source(std::size_t node,
+                                          rust::Str source_name);
+  essentia::streaming::SinkBase &sink(std::size_t node, rust::Str sink_name);
+  std::size_t add_node(essentia::streaming::Algorithm *algorithm);
+  std::size_t add_generator_candidate(essentia::streaming::Algorithm *algorithm);
+  void adopt_last_sink_parent(essentia::streaming::SourceBase &source);
+
+  template <typename T>
+  std::size_t
+  generic_add_vector_input(std::unique_ptr<DataContainer> data_container) {
+    auto &stored = _vector_inputs.emplace_back(std::move(data_container));
+    return add_generator_candidate(new essentia::streaming::VectorInput<T>(
+        &std::get<std::vector<T>>(stored->data)));
+  }
+
+  template <typename T>
+  std::size_t generic_add_vector_output(essentia::streaming::SourceBase &src) {
+    auto &stored = _vector_outputs.emplace_back(
+        std::make_unique<DataContainer>(std::vector<T>{}));
+    auto *vector_output = new essentia::streaming::VectorOutput<T>(
+        &std::get<std::vector<T>>(stored->data));
+    add_node(vector_output);
+    essentia::streaming::connect(src, vector_output->input("data"));
+    return _vector_outputs.size() - 1;
+  }
+
+public:
+  NetworkBridge();
+  ~NetworkBridge();
+
+  std::size_t
+  add_algorithm(std::unique_ptr<StreamingAlgorithmBridge> algorithm_bridge);
+  std::size_t add_vector_input(std::unique_ptr<DataContainer> data_container);
+  std::size_t add_vector_output(std::size_t node, rust::Str source_name,
+                                DataType data_type);
+
+  void set_generator(std::size_t node);
+
+  void connect(std::size_t source_node, rust::Str source_name,
+               std::size_t sink_node, rust::Str sink_name);
+  void connect_to_pool(std::size_t source_node, rust::Str source_name,
+                       rust::Str descriptor_name);
+  void connect_to_nowhere(std::size_t source_node, rust::Str source_name);
+
+  void run();
+  void reset();
+
+  const DataContainer &get_vector_output(std::size_t index) const;
+  const PoolBridge &get_pool() const;
+};
+
+std::unique_ptr<NetworkBridge> create_network_bridge();
+
+} // namespace essentia_bridge
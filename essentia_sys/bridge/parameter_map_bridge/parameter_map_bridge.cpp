@@ -102,6 +102,58 @@ void ParameterMapBridge::add(rust::Str key,
   std::visit(Visitor{_parameter_map, str_key}, data_container->data);
 }
 
+std::unique_ptr<DataContainer>
+parameter_to_data_container(const essentia::Parameter &parameter) {
+  if (!parameter.isConfigured()) {
+    return nullptr;
+  }
+
+  switch (parameter.type()) {
+  case essentia::Parameter::REAL:
+    return std::make_unique<DataContainer>(parameter.toReal());
+  case essentia::Parameter::STRING:
+    return std::make_unique<DataContainer>(parameter.toString());
+  case essentia::Parameter::BOOL:
+    return std::make_unique<DataContainer>(parameter.toBool());
+  case essentia::Parameter::INT:
+    return std::make_unique<DataContainer>(parameter.toInt());
+  case essentia::Parameter::STEREOSAMPLE:
+    return std::make_unique<DataContainer>(parameter.toStereoSample());
+  case essentia::Parameter::VECTOR_REAL:
+    return std::make_unique<DataContainer>(parameter.toVectorReal());
+  case essentia::Parameter::VECTOR_STRING:
+    return std::make_unique<DataContainer>(parameter.toVectorString());
+  case essentia::Parameter::VECTOR_BOOL:
+    return std::make_unique<DataContainer>(parameter.toVectorBool());
+  case essentia::Parameter::VECTOR_INT:
+    return std::make_unique<DataContainer>(parameter.toVectorInt());
+  case essentia::Parameter::VECTOR_STEREOSAMPLE:
+    return std::make_unique<DataContainer>(parameter.toVectorStereoSample());
+  case essentia::Parameter::VECTOR_VECTOR_REAL:
+    return std::make_unique<DataContainer>(parameter.toVectorVectorReal());
+  case essentia::Parameter::VECTOR_VECTOR_STRING:
+    return std::make_unique<DataContainer>(parameter.toVectorVectorString());
+  case essentia::Parameter::VECTOR_VECTOR_STEREOSAMPLE:
+    return std::make_unique<DataContainer>(
+        parameter.toVectorVectorStereoSample());
+  case essentia::Parameter::VECTOR_MATRIX_REAL:
+    return std::make_unique<DataContainer>(parameter.toVectorMatrixReal());
+  case essentia::Parameter::MAP_VECTOR_REAL:
+    return std::make_unique<DataContainer>(parameter.toMapVectorReal());
+  case essentia::Parameter::MAP_VECTOR_STRING:
+    return std::make_unique<DataContainer>(parameter.toMapVectorString());
+  case essentia::Parameter::MAP_VECTOR_INT:
+    return std::make_unique<DataContainer>(parameter.toMapVectorInt());
+  case essentia::Parameter::MAP_REAL:
+    return std::make_unique<DataContainer>(parameter.toMapReal());
+  case essentia::Parameter::MATRIX_REAL:
+    return std::make_unique<DataContainer>(parameter.toMatrixReal());
+  default:
+    throw std::invalid_argument{
+        "parameter_to_data_container: unsupported parameter type"};
+  }
+}
+
 std::unique_ptr<ParameterMapBridge> create_parameter_map_bridge() {
   return std::make_unique<ParameterMapBridge>();
 }
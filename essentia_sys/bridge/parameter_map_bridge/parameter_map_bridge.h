@@ -19,4 +19,7 @@ private:
   essentia::ParameterMap *_parameter_map;
 };
 
+std::unique_ptr<DataContainer>
+parameter_to_data_container(const essentia::Parameter &parameter);
+
 } // namespace essentia_bridge
\ No newline at end of file
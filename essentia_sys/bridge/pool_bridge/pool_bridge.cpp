@@ -8,11 +8,17 @@
 namespace essentia_bridge {
 
 // --- Helper Visitor for PoolBridge::set ---
+//
+// Essentia's Pool distinguishes single values (set) from series of values
+// (add). Types that only exist as series are stored by replacing the
+// descriptor with one entry per element, so that they read back unchanged.
+// Such series must not be empty. StereoSample and Array2D have no single-value
+// slot and are rejected.
 struct SetVisitor {
   essentia::Pool &pool;
   const std::string &key;
 
-  // Supported types
+  // Single values
   void operator()(const std::string &value) { pool.set(key, value); }
   void operator()(float value) {
     pool.set(key, static_cast<essentia::Real>(value));
@@ -27,9 +33,36 @@ struct SetVisitor {
         reinterpret_cast<const std::vector<essentia::Real> &>(value);
     pool.set(key, real_vector);
   }
-  // Note: Tensor<Real> is not directly in DataContainer, but might be added
-  // later. void operator()(const essentia::Tensor<essentia::Real> &value) {
-  // pool.set(key, value); }
+  void operator()(const essentia::Tensor<essentia::Real> &value) {
+    pool.set(key, value);
+  }
+
+  // Series
+  void operator()(const std::vector<essentia::StereoSample> &value) {
+    add_all(value);
+  }
+  void operator()(const std::vector<std::vector<float>> &value) {
+    add_all(value);
+  }
+  void operator()(const std::vector<std::vector<std::string>> &value) {
+    add_all(value);
+  }
+  void operator()(const std::vector<TNT::Array2D<float>> &value) {
+    add_all(value);
+  }
+
+  // The pool cannot hold an empty series, and removing the descriptor instead
+  // would make a later get fail with a missing key.
+  template <typename T> void add_all(const std::vector<T> &values) {
+    if (values.empty()) {
+      throw std::invalid_argument("Cannot set '" + key +
+                                  "' to an empty series");
+    }
+    pool.remove(key);
+    for (const auto &value : values) {
+      pool.add(key, value);
+    }
+  }
 
   // Overloads for all other types in the variant to make the visitor exhaustive
   template <typename T> void operator()(const T &) {
@@ -37,6 +70,32 @@ struct SetVisitor {
   }
 };
 
+// --- Helper Visitor for PoolBridge::add ---
+struct AddVisitor {
+  essentia::Pool &pool;
+  const std::string &key;
+
+  void operator()(float value) {
+    pool.add(key, static_cast<essentia::Real>(value));
+  }
+  void operator()(const std::string &value) { pool.add(key, value); }
+  void operator()(const std::vector<float> &value) { pool.add(key, value); }
+  void operator()(const std::vector<std::string> &value) {
+    pool.add(key, value);
+  }
+  void operator()(const essentia::StereoSample &value) {
+    pool.add(key, value);
+  }
+  void operator()(const TNT::Array2D<float> &value) { pool.add(key, value); }
+  void operator()(const essentia::Tensor<essentia::Real> &value) {
+    pool.add(key, value);
+  }
+
+  template <typename T> void operator()(const T &) {
+    throw std::runtime_error("Unsupported data type for Pool::add");
+  }
+};
+
 // --- PoolBridge Implementation ---
 
 // Owning constructor
@@ -66,6 +125,13 @@ essentia::Pool PoolBridge::into_pool() {
   return moved_pool;
 }
 
+essentia::Pool &PoolBridge::pool() {
+  if (!_pool) {
+    throw std::runtime_error("Pool pointer is null and cannot be used");
+  }
+  return *_pool;
+}
+
 // clone method
 std::unique_ptr<PoolBridge> PoolBridge::clone() const {
   auto new_bridge =
@@ -85,6 +151,43 @@ void PoolBridge::set(rust::Str key,
   std::visit(SetVisitor{*_pool, cpp_key}, data_container->data);
 }
 
+void PoolBridge::add(rust::Str key,
+                     std::unique_ptr<DataContainer> data_container) {
+  if (!_pool) {
+    throw std::runtime_error("Pool pointer is null and cannot be used");
+  }
+  std::string cpp_key(key);
+  std::visit(AddVisitor{*_pool, cpp_key}, data_container->data);
+}
+
+void PoolBridge::merge(const PoolBridge &other, rust::Str merge_type) {
+  if (!_pool || !other._pool) {
+    throw std::runtime_error("Pool pointer is null and cannot be used");
+  }
+  // Pool::merge takes a non-const reference but only reads from it
+  _pool->merge(*other._pool, std::string(merge_type));
+}
+
+void PoolBridge::remove(rust::Str key) {
+  if (!_pool) {
+    throw std::runtime_error("Pool pointer is null and cannot be used");
+  }
+  _pool->remove(std::string(key));
+}
+
+void PoolBridge::remove_namespace(rust::Str ns) {
+  if (!_pool) {
+    throw std::runtime_error("Pool pointer is null and cannot be used");
+  }
+  _pool->removeNamespace(std::string(ns));
+}
+
+void PoolBridge::clear() {
+  if (_pool) {
+    _pool->clear();
+  }
+}
+
 std::unique_ptr<DataContainer> PoolBridge::get(rust::Str key) const {
   if (!_pool) {
     throw std::runtime_error("Pool pointer is null and cannot be used");
@@ -105,7 +208,32 @@ std::unique_ptr<DataContainer> PoolBridge::get(rust::Str key) const {
     return std::make_unique<DataContainer>(
         _pool->value<std::vector<std::string>>(cpp_key));
   }
-  // Note: Add other supported types from essentia::Pool here
+  if (_pool->contains<essentia::Tensor<essentia::Real>>(cpp_key)) {
+    return std::make_unique<DataContainer>(
+        _pool->value<essentia::Tensor<essentia::Real>>(cpp_key));
+  }
+  if (_pool->contains<std::vector<essentia::StereoSample>>(cpp_key)) {
+    return std::make_unique<DataContainer>(
+        _pool->value<std::vector<essentia::StereoSample>>(cpp_key));
+  }
+  if (_pool->contains<std::vector<std::vector<essentia::Real>>>(cpp_key)) {
+    return std::make_unique<DataContainer>(
+        _pool->value<std::vector<std::vector<essentia::Real>>>(cpp_key));
+  }
+  if (_pool->contains<std::vector<std::vector<std::string>>>(cpp_key)) {
+    return std::make_unique<DataContainer>(
+        _pool->value<std::vector<std::vector<std::string>>>(cpp_key));
+  }
+  if (_pool->contains<std::vector<TNT::Array2D<essentia::Real>>>(cpp_key)) {
+    return std::make_unique<DataContainer>(
+        _pool->value<std::vector<TNT::Array2D<essentia::Real>>>(cpp_key));
+  }
+  if (_pool->contains<std::vector<essentia::Tensor<essentia::Real>>>(
+          cpp_key)) {
+    throw std::runtime_error("Key '" + cpp_key +
+                             "' holds a series of tensors, which has no "
+                             "corresponding data type.");
+  }
 
   throw std::runtime_error(
       "Key '" + cpp_key +
@@ -121,6 +249,33 @@ bool PoolBridge::contains(rust::Str key) const {
   return std::find(names.begin(), names.end(), key_str) != names.end();
 }
 
+// Whether the descriptor was accumulated with add, as opposed to set. Single
+// values are kept in the getSingle*Pool maps.
+bool PoolBridge::is_series(rust::Str key) const {
+  if (!_pool) {
+    return false;
+  }
+  const std::string key_str(key);
+  const auto has = [&key_str](const auto &descriptors) {
+    return descriptors.find(key_str) != descriptors.end();
+  };
+  return has(_pool->getRealPool()) || has(_pool->getVectorRealPool()) ||
+         has(_pool->getStringPool()) || has(_pool->getVectorStringPool()) ||
+         has(_pool->getArray2DRealPool()) ||
+         has(_pool->getStereoSamplePool()) || has(_pool->getTensorRealPool());
+}
+
+// Series of tensors are the only descriptors without a DataContainer
+// counterpart, so get() cannot return them.
+bool PoolBridge::is_tensor_series(rust::Str key) const {
+  if (!_pool) {
+    return false;
+  }
+  const std::string key_str(key);
+  const auto &tensors = _pool->getTensorRealPool();
+  return tensors.find(key_str) != tensors.end();
+}
+
 rust::Vec<rust::String> PoolBridge::keys() const {
   if (!_pool) {
     return rust::Vec<rust::String>();
@@ -133,6 +288,38 @@ rust::Vec<rust::String> PoolBridge::keys() const {
   return rust_keys;
 }
 
+rust::Vec<rust::String> PoolBridge::keys_with_prefix(rust::Str prefix) const {
+  if (!_pool) {
+    return rust::Vec<rust::String>();
+  }
+  const std::string prefix_str(prefix);
+  rust::Vec<rust::String> rust_keys;
+  for (const auto &key : _pool->descriptorNames()) {
+    if (key.compare(0, prefix_str.size(), prefix_str) == 0) {
+      rust_keys.push_back(rust::String(key));
+    }
+  }
+  return rust_keys;
+}
+
+std::size_t PoolBridge::size() const {
+  if (!_pool) {
+    return 0;
+  }
+  // A descriptor lives in exactly one of the typed maps, so counting their
+  // entries avoids building the list of names.
+  return _pool->getSingleRealPool().size() +
+         _pool->getSingleStringPool().size() +
+         _pool->getSingleVectorRealPool().size() +
+         _pool->getSingleVectorStringPool().size() +
+         _pool->getSingleTensorRealPool().size() +
+         _pool->getRealPool().size() + _pool->getVectorRealPool().size() +
+         _pool->getStringPool().size() + _pool->getVectorStringPool().size() +
+         _pool->getArray2DRealPool().size() +
+         _pool->getStereoSamplePool().size() +
+         _pool->getTensorRealPool().size();
+}
+
 // --- Factory Function ---
 std::unique_ptr<PoolBridge> create_pool_bridge() {
   return std::make_unique<PoolBridge>();
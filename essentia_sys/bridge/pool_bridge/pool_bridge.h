@@ -18,13 +18,23 @@ public:
   ~PoolBridge();
 
   essentia::Pool into_pool();
+  essentia::Pool &pool();
 
   std::unique_ptr<PoolBridge> clone() const;
 
   void set(rust::Str key, std::unique_ptr<DataContainer> data_container);
+  void add(rust::Str key, std::unique_ptr<DataContainer> data_container);
+  void merge(const PoolBridge &other, rust::Str merge_type);
+  void remove(rust::Str key);
+  void remove_namespace(rust::Str ns);
+  void clear();
   std::unique_ptr<DataContainer> get(rust::Str key) const;
   bool contains(rust::Str key) const;
+  bool is_series(rust::Str key) const;
+  bool is_tensor_series(rust::Str key) const;
   rust::Vec<rust::String> keys() const;
+  rust::Vec<rust::String> keys_with_prefix(rust::Str prefix) const;
+  std::size_t size() const;
 
 private:
   essentia::Pool *_pool;
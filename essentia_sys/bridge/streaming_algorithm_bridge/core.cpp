@@ -0,0 +1,22 @@
+#include "streaming_algorithm_bridge.h"
+
+namespace essentia_bridge {
+
+StreamingAlgorithmBridge::StreamingAlgorithmBridge(
+    essentia::streaming::Algorithm *algorithm)
+    : _algorithm(algorithm) {}
+
+StreamingAlgorithmBridge::~StreamingAlgorithmBridge() { delete _algorithm; }
+
+void StreamingAlgorithmBridge::configure(
+    std::unique_ptr<ParameterMapBridge> parameter_map_bridge) {
+  _algorithm->configure(parameter_map_bridge->get_parameter_map());
+}
+
+essentia::streaming::Algorithm *StreamingAlgorithmBridge::release() {
+  essentia::streaming::Algorithm *algorithm = _algorithm;
+  _algorithm = nullptr;
+  return algorithm;
+}
+
+} // namespace essentia_bridge
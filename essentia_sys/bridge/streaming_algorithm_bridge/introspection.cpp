@@ -0,0 +1,84 @@
+#include "../algorithm_bridge/algorithm_bridge.h"
+#include "../common/type_mapping.h"
+#include "essentia-sys/src/lib.rs.h"
+#include "essentia/algorithmfactory.h"
+#include "streaming_algorithm_bridge.h"
+
+namespace essentia_bridge {
+
+rust::String StreamingAlgorithmBridge::get_name() const {
+  return rust::String(_algorithm->name());
+}
+
+rust::String StreamingAlgorithmBridge::get_category() const {
+  try {
+    const auto &info =
+        essentia::streaming::AlgorithmFactory::getInfo(_algorithm->name());
+    return rust::String(info.category);
+  } catch (...) {
+    return rust::String("Unknown");
+  }
+}
+
+rust::String StreamingAlgorithmBridge::get_description() const {
+  try {
+    const auto &info =
+        essentia::streaming::AlgorithmFactory::getInfo(_algorithm->name());
+    return rust::String(info.description);
+  } catch (...) {
+    return rust::String("Description not available");
+  }
+}
+
+rust::Vec<ParameterInfo> StreamingAlgorithmBridge::get_parameter_infos() const {
+  return configurable_parameter_infos(*_algorithm);
+}
+
+std::unique_ptr<DataContainer> StreamingAlgorithmBridge::get_default_parameter(
+    rust::Str parameter_name) const {
+  return configurable_default_parameter(*_algorithm, parameter_name);
+}
+
+rust::Vec<InputOutputInfo> StreamingAlgorithmBridge::get_sink_infos() const {
+  rust::Vec<InputOutputInfo> sink_infos;
+
+  const auto &sink_descriptions = _algorithm->inputDescription;
+
+  for (const auto &sink_name : _algorithm->inputNames()) {
+    InputOutputInfo info;
+    info.name = sink_name;
+    info.data_type =
+        type_info_to_data_type(_algorithm->input(sink_name).typeInfo());
+
+    auto desc_it = sink_descriptions.find(sink_name);
+    info.description =
+        (desc_it != sink_descriptions.end()) ? desc_it->second : "";
+
+    sink_infos.push_back(std::move(info));
+  }
+
+  return sink_infos;
+}
+
+rust::Vec<InputOutputInfo> StreamingAlgorithmBridge::get_source_infos() const {
+  rust::Vec<InputOutputInfo> source_infos;
+
+  const auto &source_descriptions = _algorithm->outputDescription;
+
+  for (const auto &source_name : _algorithm->outputNames()) {
+    InputOutputInfo info;
+    info.name = source_name;
+    info.data_type =
+        type_info_to_data_type(_algorithm->output(source_name).typeInfo());
+
+    auto desc_it = source_descriptions.find(source_name);
+    info.description =
+        (desc_it != source_descriptions.end()) ? desc_it->second : "";
+
+    source_infos.push_back(std::move(info));
+  }
+
+  return source_infos;
+}
+
+} // namespace essentia_bridge
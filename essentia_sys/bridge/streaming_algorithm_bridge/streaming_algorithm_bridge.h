@@ -0,0 +1,36 @@
+#pragma once
+
+#include "../parameter_map_bridge/parameter_map_bridge.h"
+#include <essentia/streaming/streamingalgorithm.h>
+#include <rust/cxx.h>
+
+namespace essentia_bridge {
+
+struct ParameterInfo;
+struct InputOutputInfo;
+
+class StreamingAlgorithmBridge {
+private:
+  essentia::streaming::Algorithm *_algorithm;
+
+public:
+  StreamingAlgorithmBridge(essentia::streaming::Algorithm *algorithm);
+  ~StreamingAlgorithmBridge();
+
+  void configure(std::unique_ptr<ParameterMapBridge> parameter_map_bridge);
+
+  rust::String get_name() const;
+  rust::String get_category() const;
+  rust::String get_description() const;
+  rust::Vec<ParameterInfo> get_parameter_infos() const;
+  std::unique_ptr<DataContainer>
+  get_default_parameter(rust::Str parameter_name) const;
+  rust::Vec<InputOutputInfo> get_sink_infos() const;
+  rust::Vec<InputOutputInfo> get_source_infos() const;
+
+  // Hands the wrapped algorithm over to a network, which becomes responsible
+  // for deleting it.
+  essentia::streaming::Algorithm *release();
+};
+
+} // namespace essentia_bridge
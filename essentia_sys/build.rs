@@ -2,6 +2,7 @@ struct Library {
     pub name: String,
     pub pkg_config_name: String,
     pub link_name: Option<String>,
+    pub feature: Option<String>,
 }
 
 impl Library {
@@ -10,8 +11,331 @@ impl Library {
             name: name.to_string(),
             pkg_config_name: pkg_config_name.to_string(),
             link_name: link_name.map(|name| name.to_string()),
+            feature: None,
         }
     }
+
+    fn with_feature(mut self, feature: &str) -> Self {
+        self.feature = Some(feature.to_string());
+        self
+    }
+}
+
+fn feature_enabled(feature: &str) -> bool {
+    std::env::var_os(format!("CARGO_FEATURE_{}", feature.to_uppercase())).is_some()
+}
+
+/// Building Essentia from source for the `vendored` feature.
+#[cfg(feature = "vendored")]
+mod vendored {
+    use std::path::{Path, PathBuf};
+    use std::process::Command;
+
+    use super::feature_enabled;
+
+    /// Dependency names understood by the `--lightweight` option of Essentia's
+    /// waf build, keyed by the cargo feature enabling them. TensorFlow is not a
+    /// lightweight dependency and is enabled through `--with-tensorflow` instead.
+    const VENDORED_DEPENDENCIES: &[(&str, &str)] = &[
+        ("yaml", "yaml"),
+        ("taglib", "taglib"),
+        ("samplerate", "libsamplerate"),
+        ("chromaprint", "libchromaprint"),
+        ("ffmpeg", "libav"),
+    ];
+
+    /// A source archive downloaded by the `vendored` feature, unless the
+    /// environment variable points to an existing source tree instead.
+    struct VendoredSource {
+        name: &'static str,
+        version: &'static str,
+        url: &'static str,
+        sha256: &'static str,
+        env_var: &'static str,
+        marker: &'static str,
+    }
+
+    const EIGEN_SOURCE: VendoredSource = VendoredSource {
+        name: "Eigen",
+        version: "3.4.0",
+        url: "https://gitlab.com/libeigen/eigen/-/archive/{version}/eigen-{version}.tar.gz",
+        sha256: "8586084f71f9bde545ee7fa6d00288b264a2b7ac3607b974e54d13e7162c1c72",
+        env_var: "EIGEN_SOURCE_DIR",
+        marker: "Eigen/Core",
+    };
+
+    fn vendored_error(message: String) -> ! {
+        println!("cargo:error={}", message);
+        std::process::exit(1);
+    }
+
+    /// Returns the source tree `env_var` points to, if it is set.
+    fn local_source_dir(name: &str, env_var: &str, marker: &str) -> Option<PathBuf> {
+        println!("cargo:rerun-if-env-changed={}", env_var);
+
+        let directory = PathBuf::from(std::env::var_os(env_var)?);
+
+        if !directory.join(marker).exists() {
+            vendored_error(format!(
+                "{} sources not found in '{}' set by {}",
+                name,
+                directory.display(),
+                env_var
+            ));
+        }
+
+        Some(directory)
+    }
+
+    /// Returns the Essentia checkout to build. Essentia has not tagged a
+    /// release since 2.1_beta5, which predates the Eigen based tensors the
+    /// bridge relies on, so there is no archive to download.
+    fn essentia_source_dir() -> PathBuf {
+        local_source_dir("Essentia", "ESSENTIA_SOURCE_DIR", "wscript").unwrap_or_else(|| {
+            vendored_error(
+                "The vendored build needs an Essentia checkout, set ESSENTIA_SOURCE_DIR to one"
+                    .to_string(),
+            )
+        })
+    }
+
+    /// Returns the source tree of a vendored dependency, downloading and
+    /// verifying the pinned archive into `OUT_DIR` on the first build.
+    fn vendored_source_dir(source: &VendoredSource, out_dir: &Path) -> PathBuf {
+        if let Some(directory) = local_source_dir(source.name, source.env_var, source.marker) {
+            return directory;
+        }
+
+        let directory = out_dir.join("vendor").join(format!(
+            "{}-{}",
+            source.name.to_lowercase(),
+            source.version
+        ));
+
+        if !directory.join(source.marker).exists() {
+            download_source(source, &directory).unwrap_or_else(|err| {
+                vendored_error(format!(
+                    "Failed to download {} sources: {}",
+                    source.name, err
+                ))
+            });
+        }
+
+        directory
+    }
+
+    fn download_source(source: &VendoredSource, destination: &Path) -> std::io::Result<()> {
+        use sha2::{Digest, Sha256};
+        use std::io::Read;
+
+        let url = source.url.replace("{version}", source.version);
+        let response = ureq::get(&url)
+            .call()
+            .map_err(|err| std::io::Error::other(format!("{}: {}", url, err)))?;
+
+        let mut archive = Vec::new();
+        response.into_reader().read_to_end(&mut archive)?;
+
+        let checksum = format!("{:x}", Sha256::digest(&archive));
+        if checksum != source.sha256 {
+            return Err(std::io::Error::other(format!(
+                "checksum mismatch for {}: expected {}, got {}",
+                url, source.sha256, checksum
+            )));
+        }
+
+        // The archives contain a single top-level directory, which is moved into
+        // place once fully unpacked so an interrupted build starts over.
+        let staging = destination.with_extension("partial");
+        if staging.exists() {
+            std::fs::remove_dir_all(&staging)?;
+        }
+
+        tar::Archive::new(flate2::read::GzDecoder::new(archive.as_slice())).unpack(&staging)?;
+
+        let root = std::fs::read_dir(&staging)?
+            .next()
+            .ok_or_else(|| std::io::Error::other(format!("{} is an empty archive", url)))??;
+
+        if destination.exists() {
+            std::fs::remove_dir_all(destination)?;
+        }
+        std::fs::rename(root.path(), destination)?;
+        std::fs::remove_dir_all(&staging)
+    }
+
+    fn copy_dir_all(source: &Path, destination: &Path) -> std::io::Result<()> {
+        std::fs::create_dir_all(destination)?;
+
+        for entry in std::fs::read_dir(source)? {
+            let entry = entry?;
+            let target = destination.join(entry.file_name());
+
+            if entry.file_type()?.is_dir() {
+                if entry.file_name() != ".git" {
+                    copy_dir_all(&entry.path(), &target)?;
+                }
+            } else {
+                std::fs::copy(entry.path(), target)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Summarizes a source tree by its file count and latest modification time,
+    /// to notice when a local checkout changed since it was copied.
+    fn tree_fingerprint(directory: &Path) -> std::io::Result<(u64, u128)> {
+        let mut files = 0;
+        let mut latest = 0;
+
+        for entry in std::fs::read_dir(directory)? {
+            let entry = entry?;
+
+            if entry.file_type()?.is_dir() {
+                if entry.file_name() != ".git" {
+                    let (nested_files, nested_latest) = tree_fingerprint(&entry.path())?;
+                    files += nested_files;
+                    latest = latest.max(nested_latest);
+                }
+            } else {
+                let modified = entry
+                    .metadata()?
+                    .modified()?
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map_or(0, |duration| duration.as_nanos());
+                files += 1;
+                latest = latest.max(modified);
+            }
+        }
+
+        Ok((files, latest))
+    }
+
+    /// Copies a local source tree to `build_tree`, replacing the previous copy
+    /// whenever the source location or its content changed.
+    fn sync_build_tree(source: &Path, build_tree: &Path) -> std::io::Result<()> {
+        let (files, latest) = tree_fingerprint(source)?;
+        let stamp = format!("{}\n{}\n{}\n", source.display(), files, latest);
+        let stamp_path = build_tree.with_extension("stamp");
+
+        if build_tree.exists()
+            && std::fs::read_to_string(&stamp_path).ok().as_deref() == Some(&stamp)
+        {
+            return Ok(());
+        }
+
+        if build_tree.exists() {
+            std::fs::remove_dir_all(build_tree)?;
+        }
+
+        copy_dir_all(source, build_tree)?;
+        std::fs::write(stamp_path, stamp)
+    }
+
+    fn run_waf(source_dir: &Path, args: &[String], pkg_config_path: &Path) {
+        let python = std::env::var("PYTHON").unwrap_or_else(|_| "python3".to_string());
+
+        let mut search_path = std::ffi::OsString::from(pkg_config_path);
+        if let Some(existing) = std::env::var_os("PKG_CONFIG_PATH") {
+            search_path.push(":");
+            search_path.push(existing);
+        }
+
+        let status = Command::new(&python)
+            .arg("waf")
+            .args(args)
+            .current_dir(source_dir)
+            .env("PKG_CONFIG_PATH", search_path)
+            .status()
+            .unwrap_or_else(|err| {
+                println!("cargo:error=Failed to run '{}': {}", python, err);
+                println!("cargo:error=Building vendored Essentia requires Python 3");
+                std::process::exit(1);
+            });
+
+        if !status.success() {
+            println!(
+                "cargo:error=Essentia build step 'waf {}' failed with {}",
+                args.join(" "),
+                status
+            );
+            std::process::exit(1);
+        }
+    }
+
+    /// Builds Essentia as a static library from the checkout in
+    /// `ESSENTIA_SOURCE_DIR`, using KissFFT instead of FFTW and the pinned
+    /// Eigen headers. The optional dependencies enabled through features are
+    /// still taken from the system.
+    pub fn build_vendored_essentia(build: &mut cc::Build) {
+        let out_dir = PathBuf::from(std::env::var("OUT_DIR").unwrap());
+        let install_dir = out_dir.join("essentia");
+        let pkg_config_dir = out_dir.join("pkgconfig");
+
+        let essentia_source_dir = essentia_source_dir();
+        let eigen_source_dir = vendored_source_dir(&EIGEN_SOURCE, &out_dir);
+
+        // waf writes its lock and build files next to the sources, so the
+        // checkout is built from a copy to keep it untouched.
+        let source_dir = out_dir.join("essentia-src");
+        sync_build_tree(&essentia_source_dir, &source_dir).unwrap_or_else(|err| {
+            vendored_error(format!(
+                "Failed to copy Essentia sources from '{}': {}",
+                essentia_source_dir.display(),
+                err
+            ))
+        });
+        println!("cargo:rerun-if-changed={}", essentia_source_dir.display());
+
+        std::fs::create_dir_all(&pkg_config_dir).unwrap();
+        std::fs::write(
+            pkg_config_dir.join("eigen3.pc"),
+            format!(
+                "Name: Eigen3\nDescription: Vendored Eigen headers\nVersion: {}\nCflags: -I{}\n",
+                EIGEN_SOURCE.version,
+                eigen_source_dir.display()
+            ),
+        )
+        .unwrap();
+
+        let dependencies: Vec<&str> = VENDORED_DEPENDENCIES
+            .iter()
+            .filter(|(feature, _)| feature_enabled(feature))
+            .map(|(_, dependency)| *dependency)
+            .collect();
+
+        let jobs = std::env::var("NUM_JOBS").unwrap_or_else(|_| "1".to_string());
+
+        let mut configure_args = vec![
+            "configure".to_string(),
+            "--build-static".to_string(),
+            "--fft=KISS".to_string(),
+            format!("--lightweight={}", dependencies.join(",")),
+            format!("--prefix={}", install_dir.display()),
+        ];
+
+        if feature_enabled("tensorflow") {
+            configure_args.push("--with-tensorflow".to_string());
+        }
+
+        run_waf(&source_dir, &configure_args, &pkg_config_dir);
+        run_waf(
+            &source_dir,
+            &["build".to_string(), format!("-j{}", jobs)],
+            &pkg_config_dir,
+        );
+        run_waf(&source_dir, &["install".to_string()], &pkg_config_dir);
+
+        build.include(install_dir.join("include"));
+        build.include(&eigen_source_dir);
+
+        println!(
+            "cargo:rustc-link-search=native={}",
+            install_dir.join("lib").display()
+        );
+        println!("cargo:rustc-link-lib=static=essentia");
+    }
 }
 
 fn main() {
@@ -20,12 +344,19 @@ fn main() {
         return;
     }
 
+    if !feature_enabled("link") {
+        return;
+    }
+
     let mut build = cxx_build::bridge("src/lib.rs");
     build
         .file("bridge/bridge.cpp")
         .file("bridge/algorithm_bridge/core.cpp")
         .file("bridge/algorithm_bridge/input_output.cpp")
         .file("bridge/algorithm_bridge/introspection.cpp")
+        .file("bridge/streaming_algorithm_bridge/core.cpp")
+        .file("bridge/streaming_algorithm_bridge/introspection.cpp")
+        .file("bridge/network_bridge/network_bridge.cpp")
         .file("bridge/parameter_map_bridge/parameter_map_bridge.cpp")
         .file("bridge/pool_bridge/pool_bridge.cpp")
         .file("bridge/data_container/accessors.cpp")
@@ -34,53 +365,59 @@ fn main() {
         .file("bridge/common/type_mapping.cpp")
         .include(".");
 
-    let mut libraries = vec![
-        Library::new("essentia", "essentia", Some("essentia")),
-        Library::new("eigen3", "eigen3", None),
-        Library::new("yaml", "yaml-0.1", Some("yaml")),
-        Library::new("fftw3f", "fftw3f", Some("fftw3f")),
-        Library::new("taglib", "taglib", Some("tag")),
-        Library::new("samplerate", "samplerate", Some("samplerate")),
-        Library::new("chromaprint", "libchromaprint", Some("chromaprint")),
-        Library::new("avformat", "libavformat", Some("avformat")),
-        Library::new("swresample", "libswresample", Some("swresample")),
-        Library::new("avcodec", "libavcodec", Some("avcodec")),
-        Library::new("avutil", "libavutil", Some("avutil")),
+    let optional_libraries = vec![
+        Library::new("yaml", "yaml-0.1", Some("yaml")).with_feature("yaml"),
+        Library::new("taglib", "taglib", Some("tag")).with_feature("taglib"),
+        Library::new("samplerate", "samplerate", Some("samplerate")).with_feature("samplerate"),
+        Library::new("chromaprint", "libchromaprint", Some("chromaprint"))
+            .with_feature("chromaprint"),
+        Library::new("avformat", "libavformat", Some("avformat")).with_feature("ffmpeg"),
+        Library::new("swresample", "libswresample", Some("swresample")).with_feature("ffmpeg"),
+        Library::new("avcodec", "libavcodec", Some("avcodec")).with_feature("ffmpeg"),
+        Library::new("avutil", "libavutil", Some("avutil")).with_feature("ffmpeg"),
+        Library::new("tensorflow", "tensorflow", Some("tensorflow")).with_feature("tensorflow"),
     ];
 
-    let use_tensorflow = match std::env::var("USE_TENSORFLOW") {
-        Ok(val) => !matches!(val.to_lowercase().as_str(), "0" | "false" | "no" | "off"),
-        Err(_) => true,
+    #[cfg(feature = "vendored")]
+    let mut libraries = {
+        vendored::build_vendored_essentia(&mut build);
+        Vec::new()
     };
 
-    if use_tensorflow {
-        libraries.push(Library::new("tensorflow", "tensorflow", Some("tensorflow")))
-    } else {
-        println!("cargo:warning=Skipping tensorflow support as USE_TENSORFLOW=0");
-    }
+    #[cfg(not(feature = "vendored"))]
+    let mut libraries = {
+        vec![
+            Library::new("essentia", "essentia", Some("essentia")),
+            Library::new("eigen3", "eigen3", None),
+            Library::new("fftw3f", "fftw3f", Some("fftw3f")),
+        ]
+    };
+
+    libraries.extend(
+        optional_libraries
+            .into_iter()
+            .filter(|library| library.feature.as_deref().is_some_and(feature_enabled)),
+    );
 
     for library in libraries {
         let pkg_info = match pkg_config::probe_library(&library.pkg_config_name) {
             Ok(pkg_info) => pkg_info,
-            Err(err) => match library.name.as_str() {
-                "tensorflow" => {
-                    println!("cargo:error=Failed to find tensorflow: {}", err);
-                    println!(
-                        "cargo:error=If you intend to use essentia without tensorflow, set USE_TENSORFLOW=0"
-                    );
-                    std::process::exit(1);
-                }
-                _ => {
-                    println!(
-                        "cargo:error=Failed to find required library '{}': {}",
-                        library.pkg_config_name, err
-                    );
-                    println!(
+            Err(err) => {
+                println!(
+                    "cargo:error=Failed to find required library '{}': {}",
+                    library.pkg_config_name, err
+                );
+                match &library.feature {
+                    Some(feature) => println!(
+                        "cargo:error=If you intend to use essentia without {}, disable the `{}` feature",
+                        library.name, feature
+                    ),
+                    None => println!(
                         "cargo:error=Please install the library or check your pkg-config setup"
-                    );
-                    std::process::exit(1);
+                    ),
                 }
-            },
+                std::process::exit(1);
+            }
         };
 
         println!("{:?}", pkg_info);
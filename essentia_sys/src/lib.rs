@@ -119,6 +119,8 @@ pub mod ffi {
 
         // ===== Core types =====
         pub type AlgorithmBridge;
+        pub type StreamingAlgorithmBridge;
+        pub type NetworkBridge;
         pub type ParameterMapBridge;
         pub type PoolBridge;
         pub type DataContainer;
@@ -136,6 +138,10 @@ pub mod ffi {
         pub fn get_category(self: &AlgorithmBridge) -> String;
         pub fn get_description(self: &AlgorithmBridge) -> String;
         pub fn get_parameter_infos(self: &AlgorithmBridge) -> Vec<ParameterInfo>;
+        pub fn get_default_parameter(
+            self: &AlgorithmBridge,
+            parameter_name: &str,
+        ) -> Result<UniquePtr<DataContainer>>;
         pub fn get_input_infos(self: &AlgorithmBridge) -> Vec<InputOutputInfo>;
         pub fn get_output_infos(self: &AlgorithmBridge) -> Vec<InputOutputInfo>;
 
@@ -145,6 +151,11 @@ pub mod ffi {
             parameter_map_bridge: UniquePtr<ParameterMapBridge>,
         ) -> Result<()>;
         pub fn compute(self: Pin<&mut AlgorithmBridge>) -> Result<()>;
+        pub fn get_parameter_names(self: &AlgorithmBridge) -> Vec<String>;
+        pub fn get_parameter(
+            self: &AlgorithmBridge,
+            parameter_name: &str,
+        ) -> Result<UniquePtr<DataContainer>>;
         pub fn reset(self: Pin<&mut AlgorithmBridge>) -> Result<()>;
 
         // ===== Algorithm Bridge Input/Output =====
@@ -160,6 +171,70 @@ pub mod ffi {
         ) -> Result<()>;
         pub fn get_output(self: &AlgorithmBridge, output_name: &str) -> Result<&DataContainer>;
 
+        // ===== Streaming Algorithm Bridge Creation =====
+        pub fn get_streaming_algorithm_names() -> Vec<String>;
+        pub fn create_streaming_algorithm_bridge(
+            name: &str,
+        ) -> Result<UniquePtr<StreamingAlgorithmBridge>>;
+
+        // ===== Streaming Algorithm Bridge Introspection =====
+        pub fn get_name(self: &StreamingAlgorithmBridge) -> String;
+        pub fn get_category(self: &StreamingAlgorithmBridge) -> String;
+        pub fn get_description(self: &StreamingAlgorithmBridge) -> String;
+        pub fn get_parameter_infos(self: &StreamingAlgorithmBridge) -> Result<Vec<ParameterInfo>>;
+        pub fn get_default_parameter(
+            self: &StreamingAlgorithmBridge,
+            parameter_name: &str,
+        ) -> Result<UniquePtr<DataContainer>>;
+        pub fn get_sink_infos(self: &StreamingAlgorithmBridge) -> Result<Vec<InputOutputInfo>>;
+        pub fn get_source_infos(self: &StreamingAlgorithmBridge) -> Result<Vec<InputOutputInfo>>;
+
+        // ===== Streaming Algorithm Bridge Configuration =====
+        pub fn configure(
+            self: Pin<&mut StreamingAlgorithmBridge>,
+            parameter_map_bridge: UniquePtr<ParameterMapBridge>,
+        ) -> Result<()>;
+
+        // ===== Network Bridge =====
+        pub fn create_network_bridge() -> UniquePtr<NetworkBridge>;
+        pub fn add_algorithm(
+            self: Pin<&mut NetworkBridge>,
+            algorithm_bridge: UniquePtr<StreamingAlgorithmBridge>,
+        ) -> usize;
+        pub fn add_vector_input(
+            self: Pin<&mut NetworkBridge>,
+            data_container: UniquePtr<DataContainer>,
+        ) -> Result<usize>;
+        pub fn add_vector_output(
+            self: Pin<&mut NetworkBridge>,
+            node: usize,
+            source_name: &str,
+            data_type: DataType,
+        ) -> Result<usize>;
+        pub fn set_generator(self: Pin<&mut NetworkBridge>, node: usize) -> Result<()>;
+        pub fn connect(
+            self: Pin<&mut NetworkBridge>,
+            source_node: usize,
+            source_name: &str,
+            sink_node: usize,
+            sink_name: &str,
+        ) -> Result<()>;
+        pub fn connect_to_pool(
+            self: Pin<&mut NetworkBridge>,
+            source_node: usize,
+            source_name: &str,
+            descriptor_name: &str,
+        ) -> Result<()>;
+        pub fn connect_to_nowhere(
+            self: Pin<&mut NetworkBridge>,
+            source_node: usize,
+            source_name: &str,
+        ) -> Result<()>;
+        pub fn run(self: Pin<&mut NetworkBridge>) -> Result<()>;
+        pub fn reset(self: Pin<&mut NetworkBridge>) -> Result<()>;
+        pub fn get_vector_output(self: &NetworkBridge, index: usize) -> Result<&DataContainer>;
+        pub fn get_pool(self: &NetworkBridge) -> &PoolBridge;
+
         // ===== Data Container Constructors =====
         pub fn create_data_container_from_bool(value: bool) -> UniquePtr<DataContainer>;
         pub fn create_data_container_from_string(value: &str) -> UniquePtr<DataContainer>;
@@ -267,9 +342,30 @@ pub mod ffi {
         // ===== Pool Bridge =====
         pub fn create_pool_bridge() -> UniquePtr<PoolBridge>;
         pub fn clone(self: &PoolBridge) -> UniquePtr<PoolBridge>;
-        pub fn set(self: Pin<&mut PoolBridge>, key: &str, data_container: UniquePtr<DataContainer>);
+        pub fn set(
+            self: Pin<&mut PoolBridge>,
+            key: &str,
+            data_container: UniquePtr<DataContainer>,
+        ) -> Result<()>;
+        pub fn add(
+            self: Pin<&mut PoolBridge>,
+            key: &str,
+            data_container: UniquePtr<DataContainer>,
+        ) -> Result<()>;
+        pub fn merge(
+            self: Pin<&mut PoolBridge>,
+            other: &PoolBridge,
+            merge_type: &str,
+        ) -> Result<()>;
+        pub fn remove(self: Pin<&mut PoolBridge>, key: &str) -> Result<()>;
+        pub fn remove_namespace(self: Pin<&mut PoolBridge>, ns: &str) -> Result<()>;
+        pub fn clear(self: Pin<&mut PoolBridge>);
         pub fn get(self: &PoolBridge, key: &str) -> Result<UniquePtr<DataContainer>>;
         pub fn contains(self: &PoolBridge, key: &str) -> bool;
+        pub fn is_series(self: &PoolBridge, key: &str) -> bool;
+        pub fn is_tensor_series(self: &PoolBridge, key: &str) -> bool;
         pub fn keys(self: &PoolBridge) -> Vec<String>;
+        pub fn keys_with_prefix(self: &PoolBridge, prefix: &str) -> Vec<String>;
+        pub fn size(self: &PoolBridge) -> usize;
     }
 }